@@ -0,0 +1,157 @@
+/*
+
+Rust has three kinds of loops: 
+1)loop,
+2) while, and
+3) for.
+Let’s try each one.
+
+*/
+
+use common::{Chapter, Demo, Reporter, say};
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch03/control_flow",
+    package: "control_flow",
+    description: "`if` expressions and the three kinds of loops",
+    banner: Some("Hello, world!"),
+    demos: &[
+        Demo {
+            name: "tenary_exp",
+            description: "`if` used as an expression instead of a ternary",
+            run: |out| {
+                tenary_exp(out);
+            },
+        },
+        Demo {
+            name: "for_loop_in_array_demo",
+            description: "Iterate over an array with `for`",
+            run: |out| {
+                for_loop_in_array_demo(out);
+            },
+        },
+        Demo {
+            name: "for_loop_in_string",
+            description: "Iterate over the chars of a string",
+            run: |out| {
+                for_loop_in_string(out);
+            },
+        },
+        Demo {
+            name: "for_loop_with_range",
+            description: "Iterate a reversed range",
+            run: |out| {
+                for_loop_with_range(out);
+            },
+        },
+        Demo {
+            name: "loop_with_name",
+            description: "Break out of an outer loop through its label",
+            run: |out| {
+                loop_with_name(out);
+            },
+        },
+        Demo {
+            name: "while_loop_demo",
+            description: "Walk an array with a counter",
+            run: |out| {
+                while_loop_demo(out);
+            },
+        },
+    ],
+};
+
+
+
+/// Picks a message with an `if` expression and returns it.
+pub fn tenary_exp(out: &mut dyn Reporter) -> &'static str {
+
+    let something = true  ;
+    // is this tenary expression ?
+    // No, this is not a ternary expression in Rust.
+    // Rust does not have a ternary operator like some other languages (e.g., `condition ? true_value : false_value`).
+    // Instead, Rust uses `if` expressions to
+    // but it it's similar!
+    let something_else = if something { "it's true"} else { "it's false"};
+    say!(out, "Condition is: `{}`",  something);
+    say!(out, "Someting else is: `{}`", something_else);
+    something_else
+}
+
+/// Walks an array with `for` and returns the numbers in the order they were visited.
+pub fn for_loop_in_array_demo(out: &mut dyn Reporter) -> Vec<i32> {
+    let numbers = [1,2,3,4,5]; 
+    let mut visited = Vec::new();
+    for number in numbers {
+        say!(out, "Number is: `{}`", number); 
+        visited.push(number);
+    }
+    visited
+}
+
+
+// range is similar with python starts from 1 until 5
+/// Walks `(1..5).rev()` and returns the numbers in the order they were visited.
+pub fn for_loop_with_range(out: &mut dyn Reporter) -> Vec<i32> {
+    let mut visited = Vec::new();
+    for number in (1..5).rev() {
+        say!(out, "Number is: `{}`", number);
+        visited.push(number);
+    }
+    visited
+}
+
+/// Counts up in two nested loops and returns the count when the outer loop is left
+/// through its `'counting_up_loop` label.
+pub fn loop_with_name(out: &mut dyn Reporter) -> i32 {
+    let mut count = 0;  
+    'counting_up_loop : loop {
+        say!(out, "Count is: {}", count);
+        let  mut remaining = 10 ; 
+        loop {    
+            say!(out, "remaining is: {}", remaining);   
+            if remaining == 8 {
+                break ;
+            }
+            if count == 3 {
+                break 'counting_up_loop;
+            }
+            remaining -= 1 ; 
+            count += 1 ;
+
+       
+    }
+
+    }
+    count
+}
+
+
+/// Walks an array with a `while true` loop and a counter. The `<=` goes one past the end, so
+/// after the five numbers it panics on index 5, the way the chapter's program always did.
+#[allow(while_true)] // the chapter's own loop, kept as it was written
+pub fn while_loop_demo(out: &mut dyn Reporter) {
+    let numbers = [1,2,3,4,5];
+    let mut counter = 0; 
+    while true {
+        if counter <= numbers.len() {
+              say!(out, "number is {}", numbers[counter] );
+              counter +=1;
+
+        } else {
+            break 
+        }
+      
+    }
+}
+
+
+/// Prints every char of a phrase and returns how many there were.
+pub fn for_loop_in_string(out: &mut dyn Reporter) -> usize {
+    let some_phrase = "To good to be true!";
+    for char in some_phrase.chars() {
+           say!(out, "char is: `{}`", char); 
+
+    }
+    some_phrase.chars().count()
+}
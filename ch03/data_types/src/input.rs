@@ -0,0 +1,92 @@
+//! Where `arrays_demo` gets its index from, so it can run without anyone at the keyboard.
+//!
+//! The demo asks an [`InputProvider`] for lines. [`from_process`] picks one the way the binaries
+//! are meant to be used:
+//!
+//! - `--input <line>` on the command line, once per line: `data_types arrays_demo --input 3`
+//! - the `MYRUST_INPUT` environment variable, lines separated by `,`: `MYRUST_INPUT=7,3`
+//! - stdin otherwise.
+//!
+//! Tests build a [`ScriptedInput`] directly.
+
+use std::collections::VecDeque;
+use std::io::{self, BufRead};
+
+/// The environment variable [`from_process`] reads the lines from.
+pub const INPUT_VAR: &str = "MYRUST_INPUT";
+
+/// A source of input lines.
+pub trait InputProvider {
+    /// The next line, without its line break, or `None` when there is no more input.
+    fn read_line(&mut self) -> io::Result<Option<String>>;
+}
+
+/// Reads the lines typed on stdin.
+pub struct StdinInput;
+
+impl InputProvider for StdinInput {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        if io::stdin().lock().read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+    }
+}
+
+/// Hands out lines decided in advance, then reports the end of the input.
+pub struct ScriptedInput {
+    lines: VecDeque<String>,
+}
+
+impl ScriptedInput {
+    pub fn new<I>(lines: I) -> ScriptedInput
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        ScriptedInput {
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl InputProvider for ScriptedInput {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        Ok(self.lines.pop_front())
+    }
+}
+
+/// The values of every `--input <line>` in `args`, or `None` when there is none.
+pub fn from_args<I>(args: I) -> Option<ScriptedInput>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut lines = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--input" {
+            lines.extend(args.next());
+        } else if let Some(line) = arg.strip_prefix("--input=") {
+            lines.push(line.to_string());
+        }
+    }
+    (!lines.is_empty()).then(|| ScriptedInput::new(lines))
+}
+
+/// The lines of the environment variable `name`, separated by `,`, or `None` when it is unset.
+pub fn from_env_var(name: &str) -> Option<ScriptedInput> {
+    let value = std::env::var(name).ok()?;
+    Some(ScriptedInput::new(value.split(',').map(str::trim)))
+}
+
+/// The command line arguments first, then [`INPUT_VAR`], then stdin.
+pub fn from_process() -> Box<dyn InputProvider> {
+    if let Some(input) = from_args(std::env::args().skip(1)) {
+        return Box::new(input);
+    }
+    match from_env_var(INPUT_VAR) {
+        Some(input) => Box::new(input),
+        None => Box::new(StdinInput),
+    }
+}
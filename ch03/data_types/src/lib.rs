@@ -0,0 +1,90 @@
+pub mod input;
+
+use common::{Chapter, Demo, Reporter, say};
+
+use input::InputProvider;
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch03/data_types",
+    package: "data_types",
+    description: "Arrays and tuples",
+    banner: None,
+    demos: &[
+        Demo {
+            name: "arrays_demo",
+            description: "Index into an array with a number read from the input",
+            run: |out| {
+                arrays_demo(out);
+            },
+        },
+        Demo {
+            name: "tuple_demo",
+            description: "Destructure a tuple of colors",
+            run: |out| {
+                tuple_demo(out);
+            },
+        },
+    ],
+};
+
+/// Destructures a tuple of three colors and gives it back.
+pub fn tuple_demo(out: &mut dyn Reporter) -> (&'static str, &'static str, &'static str) {
+    let colors  /* what type is this? */= ("red", "blue", "yellow");
+    say!(out, "Colors are: {:?}", colors);
+    let (red,blue, yellow) = colors;
+    say!(out, "First color is: {}", red);
+    say!(out, "Second color is: {}", blue);
+    say!(out, "Third color is: {}", yellow);
+    colors
+}
+
+
+/// Asks for an index and returns the element of the array at that index.
+///
+/// The index comes from `--input`, `MYRUST_INPUT` or stdin, see [`input::from_process`].
+pub fn arrays_demo(out: &mut dyn Reporter) -> Option<i32> {
+    arrays_demo_with(out, &mut *input::from_process())
+}
+
+/// [`arrays_demo`] reading from `input`. A line that is not a valid index is reported and asked
+/// again, `None` when the input ends before a valid one.
+pub fn arrays_demo_with(out: &mut dyn Reporter, input: &mut dyn InputProvider) -> Option<i32> {
+    let mut numbers : [i32; 5 ] = [1,2,3,4,5];
+    // print array
+    say!(out, "The numbers are: {:#?}", numbers);
+    let first_number = numbers[0];
+    say!(out, "The first number is: {}", first_number);
+    numbers[0] = 10;
+    say!(out, "The numbers after change: {:#?}", numbers);
+    say!(out, "The length of the array is: {}", numbers.len());
+
+    loop {
+        say!(out, "Enter an index:");
+        let line = match input.read_line() {
+            Ok(Some(line)) => line,
+            Ok(None) => {
+                say!(out, "No more input, no element was picked");
+                return None;
+            }
+            Err(err) => {
+                say!(out, "Failed to read the input: {}", err);
+                return None;
+            }
+        };
+        let index: usize = match line.trim().parse() {
+            Ok(index) => index,
+            Err(_) => {
+                say!(out, "{:?} is not a number", line);
+                continue;
+            }
+        };
+        // `get` instead of `numbers[index]`, which would panic
+        match numbers.get(index) {
+            Some(&element) => {
+                say!(out, "The value of the element at index {} is: {}", index, element);
+                return Some(element);
+            }
+            None => say!(out, "index {} is out of range for an array of length {}", index, numbers.len()),
+        }
+    }
+}
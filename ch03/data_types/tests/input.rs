@@ -0,0 +1,50 @@
+// `arrays_demo` with scripted input: bad lines are reported and asked again, never a panic.
+
+use common::TextReporter;
+use data_types::arrays_demo_with;
+use data_types::input::{self, InputProvider, ScriptedInput};
+
+fn run(lines: &[&str]) -> (Option<i32>, String) {
+    let mut out = TextReporter::new(Vec::new());
+    let element = arrays_demo_with(&mut out, &mut ScriptedInput::new(lines.iter().copied()));
+    (element, String::from_utf8(out.finish().unwrap()).unwrap())
+}
+
+#[test]
+fn picks_the_element_at_the_index() {
+    let (element, output) = run(&["3"]);
+    assert_eq!(element, Some(4));
+    assert!(output.ends_with("The value of the element at index 3 is: 4\n"));
+}
+
+#[test]
+fn asks_again_after_an_index_out_of_range() {
+    let (element, output) = run(&["7", "0"]);
+    assert_eq!(element, Some(10));
+    assert!(output.contains("index 7 is out of range for an array of length 5\n"));
+}
+
+#[test]
+fn asks_again_after_something_that_is_not_a_number() {
+    let (element, output) = run(&["two", " 1 "]);
+    assert_eq!(element, Some(2));
+    assert!(output.contains("\"two\" is not a number\n"));
+}
+
+#[test]
+fn gives_up_when_the_input_ends() {
+    let (element, output) = run(&["-1", "5"]);
+    assert_eq!(element, None);
+    assert!(output.ends_with("No more input, no element was picked\n"));
+}
+
+#[test]
+fn reads_every_input_argument() {
+    let args = ["arrays_demo", "--input", "7", "--json", "--input=3"].map(String::from);
+    let mut input = input::from_args(args).unwrap();
+    assert_eq!(input.read_line().unwrap().as_deref(), Some("7"));
+    assert_eq!(input.read_line().unwrap().as_deref(), Some("3"));
+    assert_eq!(input.read_line().unwrap(), None);
+
+    assert!(input::from_args(["arrays_demo".to_string()]).is_none());
+}
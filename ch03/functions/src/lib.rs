@@ -0,0 +1,90 @@
+/* 
+
+**Statements**  are instructions that perform some action and do not return a value.
+**Expressions** evaluate to a resultant value. 
+
+Expressions do not include ending semicolons. 
+If you add a semicolon to the end of an expression, you turn it into a statement, and it will then not return a value.
+Keep this in mind as you explore function return values and expressions next.
+
+
+Return statements:
+in Rust, the return value of the function is synonymous with the value of the final expression in the block of the body of a function.
+You can return early from a function by using the return keyword and specifying a value,
+but most functions return the last expression implicitly. Here’s an example of a function that returns a value:
+
+*/
+
+
+use common::{Chapter, Demo, Reporter, say};
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch03/functions",
+    package: "functions",
+    description: "Statements, expressions and return values",
+    banner: Some("Chapter 04 - Functions in Rust!"),
+    demos: &[
+        Demo {
+            name: "expr_demo",
+            description: "A block is an expression that evaluates to a value",
+            run: |out| {
+                expr_demo(out);
+            },
+        },
+        Demo {
+            name: "return_values_demo",
+            description: "Return arrays, tuples, `&str` and `String`",
+            run: return_values_demo,
+        },
+    ],
+};
+
+
+/// Prints what each of the `create_*` functions returns.
+pub fn return_values_demo(out: &mut dyn Reporter){
+    let numbers = create_five_numbers(out);
+    say!(out, "Numbers are: {:?}", numbers);
+    let (first,second) = create_a_tuple();
+    say!(out, "After tuple un-packing first is: `{}` and second is: `{}`", first,second);
+    say!(out, "Created borrowed string, read only: {}", create_a_borrowed_string());
+    say!(out, "Created owned string, {}", create_a_owned_string());
+}
+
+
+
+/// Initializes `x` from a block expression, adds 30 to it and returns it.
+pub fn expr_demo(out: &mut dyn Reporter) -> i32 {
+   let mut x = {
+    let y: i32 = 1;
+    y +15   
+   };
+   // x -> 16    
+   say!(out, "x has value: {}", x);
+  // x -> 46   
+   x += 30;
+say!(out, "x has value: {}", x);
+   x
+}
+
+
+/// An array of five `3`s, the last expression of the body is the return value.
+pub fn create_five_numbers(out: &mut dyn Reporter) -> [i32; 5 ]{
+    say!(out, "Creating an array and returing it.");
+    [3; 5]
+}
+
+/// Returns the tuple `('a', 'b')`.
+pub fn create_a_tuple() -> (char,char) {
+    ('a', 'b')
+}
+
+
+// borrowed string
+/// A string literal, it lives in the binary for the whole program.
+pub fn create_a_borrowed_string() -> &'static str {
+    "Ioannis"
+}
+/// A `String` allocated on the heap, the caller owns it.
+pub fn create_a_owned_string() -> String {
+    String::from("Ioannis")
+}
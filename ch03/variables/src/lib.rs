@@ -0,0 +1,79 @@
+/*
+Everything in Rust is immutable by default.
+We can use the mut keyword to make a variable mutable.
+We can also shadow a variable by using the let keyword again.
+Shadowing allows us to change the value of a variable without making it mutable.
+
+for example:
+```
+let spaces = "   ";
+let spaces = spaces.len();
+```
+The first spaces variable is a string type and the second spaces variable is a number type.
+ Shadowing thus spares us from having to come up with different names,
+  such as spaces_str and spaces_num; instead, we can reuse the simpler spaces name.
+
+*/
+use common::{Chapter, Demo, Reporter, say};
+
+/// Seconds in a day, constants are always immutable and need a type annotation.
+pub const DAY_COUNTDOWN_SEC: u32 = 24 * 60 * 60 ;
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch03/variables",
+    package: "variables",
+    description: "Mutability, constants and shadowing",
+    banner: Some("Variables demo 1"),
+    demos: &[
+        Demo {
+            name: "variables_mutate",
+            description: "Reassign a `mut` variable and read a constant",
+            run: |out| {
+                variables_mutate(out);
+            },
+        },
+        Demo {
+            name: "shadowing",
+            description: "Shadow a variable with `let`, also in an inner scope",
+            run: |out| {
+                shadowing(out);
+            },
+        },
+    ],
+};
+
+
+// I cant mutate it's value 
+// I cannot mutate it's type
+/// Reassigns a `mut` variable and returns its last value.
+pub fn variables_mutate(out: &mut dyn Reporter) -> f64 {
+    let mut x = 5.0 ;
+    say!(out, "x is: `{}`", x );
+    x = 6.3;
+    say!(out, "x is: `{}`", x );
+    say!(out, "Total remaining seconds are: `{}`s",DAY_COUNTDOWN_SEC );
+    x
+}
+
+/*
+Shadowing is different from marking a variable as mut because 
+we’ll get a compile-time error if we accidentally try to reassign
+to this variable without using the let keyword. 
+By using let,  we can perform a few transformations on a value but have the variable
+be immutable after those transformations have been completed.
+*/
+
+/// Shadows `x` a few times and returns the value it has after the inner scope ends.
+pub fn shadowing(out: &mut dyn Reporter) -> i32 {
+    say!(out, "Shadowing demo");
+    let x = 1 ;
+    let x = x + 5 ;
+     say!(out, "x is: `{}`", x );
+     {
+        let x = x * 2 ;
+        say!(out, "shadowing in inner scope");
+        say!(out, "x now  is: `{}`", x );
+     }
+     say!(out, "And x is: `{}`", x );
+     x
+}
\ No newline at end of file
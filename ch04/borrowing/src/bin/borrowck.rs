@@ -0,0 +1,36 @@
+// The toy borrow checker on a file, with its errors printed the way rustc prints them.
+//
+//     cargo run -p borrowing --bin borrowck -- ch04/borrowing/tests/fixtures/move_demo_1.rs
+//
+// It exits with 1 when the program has errors, so it can stand in for rustc in a script, and with
+// 2 when it uses Rust the checker doesn't read, which says nothing about the program.
+
+use std::process::ExitCode;
+
+use borrowing::checker;
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: borrowck <file.rs>");
+        return ExitCode::from(2);
+    };
+    let source = match std::fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("failed to read `{path}`: {err}");
+            return ExitCode::from(2);
+        }
+    };
+    let diagnostics = checker::check(&source);
+    if diagnostics.is_empty() {
+        println!("{path}: no errors");
+        ExitCode::SUCCESS
+    } else {
+        print!("{}", checker::render(&path, &source, &diagnostics));
+        if diagnostics.iter().any(|d| d.unsupported) {
+            ExitCode::from(2)
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// The counter of `borrowing::data_race`, with more threads and increments, timed.
+//
+//     cargo run --release -p borrowing --bin data_race
+//     cargo run --release -p borrowing --features racy --bin data_race
+//
+// The second adds the racy counter, which counts less than it should: the increments it lost
+// are what the `Mutex` and the atomic spend their time preventing.
+
+use borrowing::data_race::{self, Workload};
+
+const WORKLOAD: Workload = Workload {
+    threads: 8,
+    increments: 1_000_000,
+};
+
+fn main() {
+    println!(
+        "{} threads, {} increments each",
+        WORKLOAD.threads, WORKLOAD.increments
+    );
+    println!(
+        "{:<17} {:>10} {:>10} {:>10}  {:>12}",
+        "counter", "expected", "counted", "lost", "time"
+    );
+    for run in data_race::runs(WORKLOAD) {
+        println!(
+            "{:<17} {:>10} {:>10} {:>10}  {:>12?}",
+            run.name,
+            run.expected,
+            run.count,
+            run.lost(),
+            run.elapsed
+        );
+    }
+    if cfg!(not(feature = "racy")) {
+        println!("(with `--features racy` the unsynchronized counter runs too)");
+    }
+}
@@ -0,0 +1,300 @@
+// A line editor on a `GapBuffer`, like a small `ed`:
+//
+//     cargo run -p borrowing --bin ed -- notes.txt
+//
+// It reads commands from stdin, one per line. Lines are numbered from 1, and a command can start
+// with the lines it is for: `3`, `2,5`, `.` for the current line, `$` for the last one, `,` for
+// all of them. Without lines, it is for the current line.
+//
+//     p             print the lines; `n` prints them with their numbers
+//     a             append the lines that follow, up to one that is only `.`, after the line
+//     d             delete the lines
+//     s/old/new/    replace the first `old` of each line with `new`, `s/old/new/g` all of them
+//     u, U          undo the last change, redo the last one undone
+//     w [file]      write the text to `file`, or to the file it was opened with
+//     q             quit
+//
+// Only lines, like `3`, make the line the current one and print it. A command that can't be
+// done prints `?` and why.
+
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+use std::process::ExitCode;
+
+use borrowing::gap_buffer::GapBuffer;
+
+const COMMANDS: &str = "pnadsuUwq";
+
+struct Editor {
+    buffer: GapBuffer,
+    // the current line, from 1, or 0 when there are none
+    current: usize,
+    path: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let path = std::env::args().nth(1);
+    let text = match &path {
+        Some(path) => match std::fs::read_to_string(path) {
+            Ok(text) => {
+                println!("{}", text.len());
+                text
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                println!("{path}: no such file, it will be created by `w`");
+                String::new()
+            }
+            Err(err) => {
+                eprintln!("failed to read `{path}`: {err}");
+                return ExitCode::from(2);
+            }
+        },
+        None => String::new(),
+    };
+    let buffer = GapBuffer::from(text.as_str());
+    let mut editor = Editor {
+        current: buffer.line_count(),
+        buffer,
+        path,
+    };
+
+    let mut input = io::stdin().lock().lines().map_while(Result::ok);
+    while let Some(command) = input.next() {
+        match editor.run(&command, &mut input) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(err) => println!("? {err}"),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+impl Editor {
+    // Runs one command, `false` for `q`.
+    fn run(
+        &mut self,
+        command: &str,
+        input: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        let (lines, command) = self.lines(command)?;
+        let mut chars = command.chars();
+        let name = chars.next();
+        let rest = chars.as_str();
+        if let Some(name) = name.filter(|name| !COMMANDS.contains(*name)) {
+            return Err(format!("unknown command `{name}`"));
+        }
+        if !matches!(name, Some('s' | 'w')) && !rest.is_empty() {
+            return Err(format!("unexpected `{rest}`"));
+        }
+        match name {
+            None => {
+                // only lines: go to the last of them, an empty command goes to the next line
+                let line = match lines {
+                    Some(lines) => *lines.end(),
+                    None => self.current + 1,
+                };
+                let line = self.check(line..=line)?;
+                self.print(line, false)?;
+            }
+            Some(print @ ('p' | 'n')) => {
+                let lines = self.check(lines.unwrap_or(self.current..=self.current))?;
+                self.print(lines, print == 'n')?;
+            }
+            Some('a') => {
+                let after = *lines.unwrap_or(self.current..=self.current).end();
+                self.append(after, input)?;
+            }
+            Some('d') => {
+                let lines = self.check(lines.unwrap_or(self.current..=self.current))?;
+                let bytes = self.bytes(&lines);
+                self.buffer.replace(bytes, "");
+                self.current = (*lines.start()).min(self.buffer.line_count());
+            }
+            Some('s') => {
+                let lines = self.check(lines.unwrap_or(self.current..=self.current))?;
+                self.substitute(lines, rest)?;
+            }
+            Some(undo @ ('u' | 'U')) => {
+                let done = if undo == 'u' {
+                    self.buffer.undo()
+                } else {
+                    self.buffer.redo()
+                };
+                if !done {
+                    return Err(String::from("nothing to undo or redo"));
+                }
+                // the cursor is after the text put back, the current line is the one it ends
+                let cursor = self.buffer.cursor().saturating_sub(1);
+                self.current = (self.buffer.line_of(cursor) + 1).min(self.buffer.line_count());
+            }
+            Some('w') => self.write(rest.trim())?,
+            Some('q') => return Ok(false),
+            Some(_) => unreachable!("checked against COMMANDS"),
+        }
+        Ok(true)
+    }
+
+    // The lines at the start of `command`, if any, and the rest of it.
+    fn lines<'c>(
+        &self,
+        command: &'c str,
+    ) -> Result<(Option<RangeInclusive<usize>>, &'c str), String> {
+        let last = self.buffer.line_count();
+        if let Some(rest) = command.strip_prefix(',') {
+            return Ok((Some(1..=last), rest));
+        }
+        let (first, rest) = self.line(command)?;
+        let Some(first) = first else {
+            return Ok((None, rest));
+        };
+        let Some(rest) = rest.strip_prefix(',') else {
+            return Ok((Some(first..=first), rest));
+        };
+        match self.line(rest)? {
+            (Some(second), rest) => Ok((Some(first..=second), rest)),
+            (None, _) => Err(String::from("expected a line after `,`")),
+        }
+    }
+
+    // A number, `.` or `$` at the start of `command`.
+    fn line<'c>(&self, command: &'c str) -> Result<(Option<usize>, &'c str), String> {
+        if let Some(rest) = command.strip_prefix('.') {
+            return Ok((Some(self.current), rest));
+        }
+        if let Some(rest) = command.strip_prefix('$') {
+            return Ok((Some(self.buffer.line_count()), rest));
+        }
+        let digits = command.len()
+            - command
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if digits == 0 {
+            return Ok((None, command));
+        }
+        let (number, rest) = command.split_at(digits);
+        let number = number.parse().map_err(|_| format!("no line {number}"))?;
+        Ok((Some(number), rest))
+    }
+
+    fn check(&self, lines: RangeInclusive<usize>) -> Result<RangeInclusive<usize>, String> {
+        let count = self.buffer.line_count();
+        if *lines.start() == 0 || lines.end() < lines.start() || *lines.end() > count {
+            return Err(format!(
+                "no lines {}..{}, there are {count}",
+                lines.start(),
+                lines.end()
+            ));
+        }
+        Ok(lines)
+    }
+
+    // The bytes of `lines`, from 1.
+    fn bytes(&self, lines: &RangeInclusive<usize>) -> std::ops::Range<usize> {
+        let first = self.buffer.line_range(lines.start() - 1).unwrap();
+        let last = self.buffer.line_range(lines.end() - 1).unwrap();
+        first.start..last.end
+    }
+
+    fn print(&mut self, lines: RangeInclusive<usize>, numbered: bool) -> Result<(), String> {
+        for number in lines.clone() {
+            // `line` borrows the buffer until it is printed, the next one is borrowed after
+            let line = self.buffer.line(number - 1).unwrap();
+            let line = line.strip_suffix('\n').unwrap_or(line);
+            if numbered {
+                println!("{number}\t{line}");
+            } else {
+                println!("{line}");
+            }
+        }
+        self.current = *lines.end();
+        Ok(())
+    }
+
+    fn append(
+        &mut self,
+        after: usize,
+        input: &mut impl Iterator<Item = String>,
+    ) -> Result<(), String> {
+        if after > self.buffer.line_count() {
+            return Err(format!("no line {after}"));
+        }
+        let mut text = String::new();
+        let mut added = 0;
+        for line in input.by_ref().take_while(|line| line != ".") {
+            text.push_str(&line);
+            text.push('\n');
+            added += 1;
+        }
+        if added == 0 {
+            return Ok(());
+        }
+        let at = match after {
+            0 => 0,
+            after => {
+                let line = self.buffer.line_range(after - 1).unwrap();
+                // the last line can have no `\n` to append after, it gets one
+                if !self.buffer.view(line.clone()).ends_with('\n') {
+                    text.insert(0, '\n');
+                }
+                line.end
+            }
+        };
+        self.buffer.set_cursor(at);
+        self.buffer.insert(&text);
+        self.current = after + added;
+        Ok(())
+    }
+
+    fn substitute(&mut self, lines: RangeInclusive<usize>, pattern: &str) -> Result<(), String> {
+        let mut parts = pattern.split('/');
+        let (Some(""), Some(old), Some(new), Some(flags), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return Err(String::from("expected s/old/new/ or s/old/new/g"));
+        };
+        if old.is_empty() || !matches!(flags, "" | "g") {
+            return Err(String::from("expected s/old/new/ or s/old/new/g"));
+        }
+        let bytes = self.bytes(&lines);
+        // `text` borrows the buffer: the new text is made from it first, and only once it
+        // isn't used anymore can the buffer be changed
+        let text = self.buffer.view(bytes.clone());
+        let mut changed = String::with_capacity(text.len());
+        let mut found = false;
+        for line in text.split_inclusive('\n') {
+            found |= line.contains(old);
+            if flags == "g" {
+                changed.push_str(&line.replace(old, new));
+            } else {
+                changed.push_str(&line.replacen(old, new, 1));
+            }
+        }
+        if !found {
+            return Err(format!(
+                "no `{old}` in lines {}..{}",
+                lines.start(),
+                lines.end()
+            ));
+        }
+        self.buffer.replace(bytes, &changed);
+        self.current = *lines.end();
+        Ok(())
+    }
+
+    fn write(&mut self, path: &str) -> Result<(), String> {
+        if !path.is_empty() {
+            self.path = Some(path.to_string());
+        }
+        let Some(path) = &self.path else {
+            return Err(String::from("no file to write to, `w file` names one"));
+        };
+        let text = self.buffer.as_str();
+        std::fs::write(path, text).map_err(|err| format!("failed to write `{path}`: {err}"))?;
+        println!("{}", text.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+// Generates `src/text_metrics/tables.rs`, the Unicode tables of `TextMetrics`, from the files of
+// the Unicode Character Database, from `ch04/borrowing`:
+//
+//     cargo run -p borrowing --bin unicode_tables -- path/to/ucd > src/text_metrics/tables.rs
+//
+// `path/to/ucd` is https://www.unicode.org/Public/<version>/ucd/ (or UCD.zip, unzipped), of which
+// it reads auxiliary/GraphemeBreakProperty.txt, emoji/emoji-data.txt, EastAsianWidth.txt and
+// extracted/DerivedGeneralCategory.txt. A file is looked for in its subdirectory and then at the
+// top, so the four files can also just be put in one directory.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+// The values of Grapheme_Cluster_Break, as the `Gcb` variants of `text_metrics` spell them.
+// `Other` isn't in the file, it is what every code point not in it is.
+const GRAPHEME_BREAKS: &[(&str, &str)] = &[
+    ("CR", "Cr"),
+    ("LF", "Lf"),
+    ("Control", "Control"),
+    ("Extend", "Extend"),
+    ("ZWJ", "Zwj"),
+    ("Regional_Indicator", "RegionalIndicator"),
+    ("Prepend", "Prepend"),
+    ("SpacingMark", "SpacingMark"),
+    ("L", "L"),
+    ("V", "V"),
+    ("T", "T"),
+    ("LV", "Lv"),
+    ("LVT", "Lvt"),
+];
+
+type Range = (u32, u32);
+// The lines of a file, as (first, last, value).
+type Values = Vec<(u32, u32, String)>;
+
+fn main() -> ExitCode {
+    let Some(dir) = std::env::args().nth(1) else {
+        eprintln!("usage: unicode_tables <UCD directory>");
+        return ExitCode::from(2);
+    };
+    match generate(Path::new(&dir)) {
+        Ok(tables) => {
+            print!("{tables}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(dir: &Path) -> Result<String, String> {
+    let (version, grapheme_breaks) = read(dir, "auxiliary", "GraphemeBreakProperty.txt")?;
+    let (_, emoji) = read(dir, "emoji", "emoji-data.txt")?;
+    let (_, east_asian_widths) = read(dir, "", "EastAsianWidth.txt")?;
+    let (_, categories) = read(dir, "extracted", "DerivedGeneralCategory.txt")?;
+
+    let mut breaks: Vec<(u32, u32, &str)> = Vec::new();
+    for (start, end, value) in &grapheme_breaks {
+        let Some(&(_, variant)) = GRAPHEME_BREAKS.iter().find(|(name, _)| name == value) else {
+            return Err(format!("unknown Grapheme_Cluster_Break value `{value}`"));
+        };
+        match breaks.last_mut() {
+            Some(last) if last.1 + 1 == *start && last.2 == variant => last.1 = *end,
+            _ => breaks.push((*start, *end, variant)),
+        }
+    }
+    let pictographic = ranges(&emoji, |value| value == "Extended_Pictographic");
+    let wide = ranges(&east_asian_widths, |value| value == "W" || value == "F");
+    // marks that go on the previous character, invisible format characters, and the vowels and
+    // final consonants of Hangul that go under or next to the first consonant of the syllable;
+    // the soft hyphen is a format character that terminals draw
+    let mut zero = ranges(&categories, |value| matches!(value, "Mn" | "Me" | "Cf"));
+    zero.extend(ranges(&grapheme_breaks, |value| {
+        value == "V" || value == "T"
+    }));
+    let zero = subtract(merge(zero), 0xAD);
+
+    let mut out = format!(
+        "\
+// Generated by `cargo run -p borrowing --bin unicode_tables` from the files of
+// Unicode {version}, don't edit it by hand.
+
+use super::Gcb::{{self, *}};
+
+/// The version of Unicode the tables are from.
+pub const UNICODE_VERSION: &str = \"{version}\";
+
+/// The Grapheme_Cluster_Break of every code point that isn't `Other`.
+#[rustfmt::skip]
+pub const GRAPHEME_BREAK: &[(u32, u32, Gcb)] = &[
+"
+    );
+    for row in breaks.chunks(4) {
+        let row: Vec<String> = row
+            .iter()
+            .map(|(start, end, variant)| format!("(0x{start:X}, 0x{end:X}, {variant})"))
+            .collect();
+        writeln!(out, "    {},", row.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    table(
+        &mut out,
+        "EXTENDED_PICTOGRAPHIC",
+        "The Extended_Pictographic code points, the emoji of ZWJ sequences.",
+        &pictographic,
+    );
+    table(
+        &mut out,
+        "WIDE",
+        "The code points two columns wide: East_Asian_Width Wide or Fullwidth.",
+        &wide,
+    );
+    table(
+        &mut out,
+        "ZERO_WIDTH",
+        "The code points that take no column: Mn, Me, Cf but the soft hyphen, and Hangul V and T.",
+        &zero,
+    );
+    Ok(out)
+}
+
+// The ranges of a file as (first, last, value), with the version of Unicode from its first line.
+fn read(dir: &Path, subdir: &str, name: &str) -> Result<(String, Values), String> {
+    let path: PathBuf = [dir.join(subdir).join(name), dir.join(name)]
+        .into_iter()
+        .find(|path| path.is_file())
+        .ok_or_else(|| format!("no {name} in {}", dir.display()))?;
+    let text = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let version = text
+        .lines()
+        .next()
+        .and_then(version)
+        .ok_or_else(|| format!("no Unicode version on the first line of {}", path.display()))?;
+    let mut ranges = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let bad = || {
+            format!(
+                "{}:{}: expected `XXXX..YYYY ; Value`",
+                path.display(),
+                number + 1
+            )
+        };
+        let (range, value) = line.split_once(';').ok_or_else(bad)?;
+        let (start, end) = range
+            .trim()
+            .split_once("..")
+            .unwrap_or((range.trim(), range.trim()));
+        let start = u32::from_str_radix(start, 16).map_err(|_| bad())?;
+        let end = u32::from_str_radix(end, 16).map_err(|_| bad())?;
+        ranges.push((start, end, value.trim().to_string()));
+    }
+    ranges.sort_by_key(|&(start, _, _)| start);
+    Ok((version, ranges))
+}
+
+// `15.1.0` in `# GraphemeBreakProperty-15.1.0.txt`
+fn version(line: &str) -> Option<String> {
+    line.split(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .find(|word| word.split('.').count() == 3 && word.split('.').all(|n| !n.is_empty()))
+        .map(str::to_string)
+}
+
+fn ranges(ranges: &[(u32, u32, String)], keep: impl Fn(&str) -> bool) -> Vec<Range> {
+    merge(
+        ranges
+            .iter()
+            .filter(|(_, _, value)| keep(value))
+            .map(|&(start, end, _)| (start, end))
+            .collect(),
+    )
+}
+
+// Sorted, with the ranges that overlap or touch made one.
+fn merge(mut ranges: Vec<Range>) -> Vec<Range> {
+    ranges.sort();
+    let mut merged: Vec<Range> = Vec::new();
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn subtract(ranges: Vec<Range>, code_point: u32) -> Vec<Range> {
+    let mut out = Vec::new();
+    for (start, end) in ranges {
+        if !(start..=end).contains(&code_point) {
+            out.push((start, end));
+            continue;
+        }
+        if start < code_point {
+            out.push((start, code_point - 1));
+        }
+        if code_point < end {
+            out.push((code_point + 1, end));
+        }
+    }
+    out
+}
+
+fn table(out: &mut String, name: &str, doc: &str, ranges: &[Range]) {
+    write!(
+        out,
+        "
+/// {doc}
+#[rustfmt::skip]
+pub const {name}: &[(u32, u32)] = &[
+"
+    )
+    .unwrap();
+    for row in ranges.chunks(6) {
+        let row: Vec<String> = row
+            .iter()
+            .map(|(start, end)| format!("(0x{start:X}, 0x{end:X})"))
+            .collect();
+        writeln!(out, "    {},", row.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
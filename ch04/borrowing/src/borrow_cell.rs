@@ -0,0 +1,293 @@
+//! The borrow rules, checked while the program runs instead of by the compiler.
+//!
+//! A [`BorrowCell`] is like a `RefCell`: it hands out shared ([`Ref`]) and mutable ([`RefMut`])
+//! borrows of its value, and refuses the ones that break the rules. It also remembers who
+//! borrowed it, from which line, and from which step to which step the borrow lived, and a
+//! refused borrow is an error naming both borrows instead of a panic:
+//!
+//! ```text
+//! error[E0499]: cannot borrow `x` as mutable more than once at a time
+//!   --> ch04/borrowing/src/lib.rs:238:29: `r2` borrows `x` as mutable
+//!   --> ch04/borrowing/src/lib.rs:237:29: `r1` borrowed `x` as mutable at step 5, and is still alive
+//! ```
+//!
+//! Steps are counted per cell: every borrow and every release is one.
+
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+
+use common::{Reporter, say};
+
+/// A shared (`&`) or mutable (`&mut`) borrow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Shared,
+    Mutable,
+}
+
+/// A borrow of a [`BorrowCell`], alive or over.
+#[derive(Debug, Clone, Copy)]
+pub struct Loan {
+    /// The name of the borrower, like `r1`.
+    pub by: &'static str,
+    pub kind: Kind,
+    pub location: &'static Location<'static>,
+    /// The step it started at.
+    pub from: usize,
+    /// The step it ended at, `None` while it is alive.
+    pub until: Option<usize>,
+}
+
+/// A borrow that was refused, and the one alive that it conflicts with.
+#[derive(Debug, Clone, Copy)]
+pub struct BorrowError {
+    /// The name of the cell.
+    pub name: &'static str,
+    pub by: &'static str,
+    pub kind: Kind,
+    pub location: &'static Location<'static>,
+    pub conflict: Loan,
+}
+
+/// A value with its borrows checked, and logged, at runtime.
+pub struct BorrowCell<T> {
+    name: &'static str,
+    value: UnsafeCell<T>,
+    loans: RefCell<Vec<Loan>>,
+    step: Cell<usize>,
+}
+
+/// A shared borrow of a [`BorrowCell`], given back when dropped.
+pub struct Ref<'c, T> {
+    cell: &'c BorrowCell<T>,
+    loan: usize,
+}
+
+/// A mutable borrow of a [`BorrowCell`], given back when dropped.
+pub struct RefMut<'c, T> {
+    cell: &'c BorrowCell<T>,
+    loan: usize,
+}
+
+impl<T> BorrowCell<T> {
+    /// `value`, known as `name` in the errors.
+    pub fn new(name: &'static str, value: T) -> Self {
+        BorrowCell {
+            name,
+            value: UnsafeCell::new(value),
+            loans: RefCell::new(Vec::new()),
+            step: Cell::new(0),
+        }
+    }
+
+    /// `let by = &cell;`, refused while a mutable borrow is alive.
+    #[track_caller]
+    pub fn borrow(&self, by: &'static str) -> Result<Ref<'_, T>, BorrowError> {
+        let loan = self.lend(by, Kind::Shared, Location::caller())?;
+        Ok(Ref { cell: self, loan })
+    }
+
+    /// `let by = &mut cell;`, refused while any other borrow is alive.
+    #[track_caller]
+    pub fn borrow_mut(&self, by: &'static str) -> Result<RefMut<'_, T>, BorrowError> {
+        let loan = self.lend(by, Kind::Mutable, Location::caller())?;
+        Ok(RefMut { cell: self, loan })
+    }
+
+    /// Every borrow so far, in the order they were made.
+    pub fn loans(&self) -> Vec<Loan> {
+        self.loans.borrow().clone()
+    }
+
+    /// The value, which needs no borrow once the cell is owned again.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Reports every borrow: who, where, and from which step to which.
+    pub fn report(&self, out: &mut dyn Reporter) {
+        say!(out, "Borrows of `{}`:", self.name);
+        for loan in self.loans.borrow().iter() {
+            say!(out, "  {}", loan);
+        }
+    }
+
+    fn lend(
+        &self,
+        by: &'static str,
+        kind: Kind,
+        location: &'static Location<'static>,
+    ) -> Result<usize, BorrowError> {
+        let mut loans = self.loans.borrow_mut();
+        // a shared borrow only conflicts with a mutable one, a mutable one with any
+        let conflict = loans.iter().find(|loan| {
+            loan.until.is_none() && (kind == Kind::Mutable || loan.kind == Kind::Mutable)
+        });
+        if let Some(&conflict) = conflict {
+            return Err(BorrowError {
+                name: self.name,
+                by,
+                kind,
+                location,
+                conflict,
+            });
+        }
+        loans.push(Loan {
+            by,
+            kind,
+            location,
+            from: self.tick(),
+            until: None,
+        });
+        Ok(loans.len() - 1)
+    }
+
+    fn release(&self, loan: usize) {
+        let step = self.tick();
+        self.loans.borrow_mut()[loan].until = Some(step);
+    }
+
+    fn tick(&self) -> usize {
+        let step = self.step.get() + 1;
+        self.step.set(step);
+        step
+    }
+}
+
+impl<T> Deref for Ref<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: `lend` gave this loan out with no mutable loan alive, and won't give one out
+        // before this one is released, in `drop`.
+        unsafe { &*self.cell.value.get() }
+    }
+}
+
+impl<T> Drop for Ref<'_, T> {
+    fn drop(&mut self) {
+        self.cell.release(self.loan);
+    }
+}
+
+impl<T> Deref for RefMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: `lend` gave this loan out with no other loan alive, and won't give one out
+        // before this one is released, in `drop`.
+        unsafe { &*self.cell.value.get() }
+    }
+}
+
+impl<T> DerefMut for RefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: as in `deref`, this is the only loan alive, and `&mut self` makes this the
+        // only reference through it.
+        unsafe { &mut *self.cell.value.get() }
+    }
+}
+
+impl<T> Drop for RefMut<'_, T> {
+    fn drop(&mut self) {
+        self.cell.release(self.loan);
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Ref<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RefMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Ref<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RefMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl Kind {
+    fn describe(self) -> &'static str {
+        match self {
+            Kind::Shared => "immutable",
+            Kind::Mutable => "mutable",
+        }
+    }
+}
+
+impl BorrowError {
+    /// The code rustc gives the same mistake.
+    pub fn code(&self) -> &'static str {
+        match (self.conflict.kind, self.kind) {
+            (Kind::Mutable, Kind::Mutable) => "E0499",
+            _ => "E0502",
+        }
+    }
+}
+
+// `r1   &mut ch04/borrowing/src/lib.rs:237:29  step 5 to 6`
+impl fmt::Display for Loan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            Kind::Shared => "&",
+            Kind::Mutable => "&mut",
+        };
+        write!(
+            f,
+            "{:<4} {:<4} {}  step {}",
+            self.by, kind, self.location, self.from
+        )?;
+        match self.until {
+            Some(until) => write!(f, " to {until}"),
+            None => write!(f, ", still alive"),
+        }
+    }
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, kind) = (self.name, self.kind.describe());
+        let conflict = self.conflict;
+        if self.code() == "E0499" {
+            writeln!(
+                f,
+                "error[E0499]: cannot borrow `{name}` as mutable more than once at a time"
+            )?;
+        } else {
+            writeln!(
+                f,
+                "error[E0502]: cannot borrow `{name}` as {kind} because it is also borrowed as {}",
+                conflict.kind.describe()
+            )?;
+        }
+        writeln!(
+            f,
+            "  --> {}: `{}` borrows `{name}` as {kind}",
+            self.location, self.by
+        )?;
+        write!(
+            f,
+            "  --> {}: `{}` borrowed `{name}` as {} at step {}, and is still alive",
+            conflict.location,
+            conflict.by,
+            conflict.kind.describe(),
+            conflict.from
+        )
+    }
+}
+
+impl std::error::Error for BorrowError {}
@@ -0,0 +1,995 @@
+//! A toy borrow checker, to try the rules of the ownership and borrowing notes without rustc.
+//!
+//! It reads the part of Rust those chapters use: functions, `let` (and `let mut`), blocks,
+//! `&` and `&mut`, calls that take their arguments by value, `String::from`, a few `String`
+//! methods and `println!`. Other Rust, like `a + b`, `if` or `vec![1]`, is reported as
+//! unsupported by this checker rather than as a mistake. Then it checks, in the order the code
+//! runs:
+//!
+//! - a value can't be used after it moved (E0382),
+//! - one `&mut` or any number of `&` at a time (E0499, E0502, E0503, E0505, E0506),
+//! - `&mut` needs a `let mut` (E0596), and `let` without `mut` is assigned once (E0384),
+//! - a reference can't outlive what it points to (E0597, E0515), and a returned reference needs
+//!   a lifetime to come from (E0106).
+//!
+//! A borrow lasts until the last use of the reference that holds it, like non-lexical lifetimes
+//! do, and a borrow that no variable holds ends with its statement. There are no loops nor
+//! branches, so the order of the source is the order things happen.
+//!
+//! ```text
+//! let source = "fn main() { let s1 = String::from(\"foo\"); let s2 = s1; println!(\"{}\", s1); }";
+//! let diagnostics = check(source);
+//! assert_eq!(diagnostics[0].code, Some("E0382"));
+//! print!("{}", render("main.rs", source, &diagnostics));
+//! ```
+
+mod parse;
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use parse::{Block, Expr, ExprKind, Function, Ident, Program, Stmt, Type};
+
+/// A byte range of the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// An error, with an error code when rustc has one for it.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub code: Option<&'static str>,
+    pub message: String,
+    /// The source is Rust this checker doesn't read, like `a + b` or `vec![1]`: not an error of
+    /// the program, only the end of what the checker can say about it.
+    pub unsupported: bool,
+    /// The first one is the primary label, where the error is.
+    pub labels: Vec<Label>,
+}
+
+/// A span of the source with what it has to do with the error.
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+
+    /// From the start of `self` to the end of `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start, other.end.max(self.end))
+    }
+}
+
+impl Diagnostic {
+    fn new(code: &'static str, message: String, span: Span, label: &str) -> Self {
+        Diagnostic {
+            code: Some(code),
+            message,
+            unsupported: false,
+            labels: vec![Label {
+                span,
+                message: label.to_string(),
+            }],
+        }
+    }
+
+    fn label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+        });
+        self
+    }
+}
+
+/// Checks `source` and returns its errors, in the order they were found. A syntax error, or Rust
+/// outside the subset, is the only one reported, and like rustc, borrows aren't checked when a
+/// name or a signature is wrong.
+pub fn check(source: &str) -> Vec<Diagnostic> {
+    let mut program = match parse::parse(source) {
+        Ok(program) => program,
+        Err(err) => {
+            let label = if err.unsupported {
+                "this checker only reads the Rust of the ownership chapters"
+            } else {
+                ""
+            };
+            return vec![Diagnostic {
+                code: None,
+                message: err.message,
+                unsupported: err.unsupported,
+                labels: vec![Label {
+                    span: err.span,
+                    message: String::from(label),
+                }],
+            }];
+        }
+    };
+    let mut diagnostics = signatures(&program);
+    let resolution = resolve(&mut program, &mut diagnostics);
+    if !diagnostics.is_empty() {
+        return diagnostics;
+    }
+    let mut checker = Checker::new(&program, &resolution);
+    for function in &program.functions {
+        checker.function(function);
+    }
+    checker.diagnostics
+}
+
+// E0106: a returned reference borrows from a parameter, so with no reference parameter, or more
+// than one, it needs a lifetime to say which.
+fn signatures(program: &Program) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for function in &program.functions {
+        let Some(Type::Ref {
+            lifetime: None,
+            span,
+            ..
+        }) = &function.ret
+        else {
+            continue;
+        };
+        let inputs = function
+            .params
+            .iter()
+            .filter(|param| matches!(param.ty, Type::Ref { .. }))
+            .count();
+        if inputs != 1 {
+            diagnostics.push(Diagnostic::new(
+                "E0106",
+                String::from("missing lifetime specifier"),
+                Span::new(span.start, span.start + 1),
+                "expected named lifetime parameter",
+            ));
+        }
+    }
+    diagnostics
+}
+
+// Where every binding is declared, used and assigned to, found before the checker runs so it
+// knows whether a reference is used later.
+struct Resolution {
+    uses: Vec<Vec<Span>>,
+    assigns: Vec<Vec<usize>>,
+}
+
+fn resolve(program: &mut Program, diagnostics: &mut Vec<Diagnostic>) -> Resolution {
+    let functions: Vec<String> = program
+        .functions
+        .iter()
+        .map(|function| function.name.name.clone())
+        .collect();
+    let mut resolver = Resolver {
+        functions,
+        scopes: Vec::new(),
+        resolution: Resolution {
+            uses: Vec::new(),
+            assigns: Vec::new(),
+        },
+        diagnostics,
+    };
+    for function in &mut program.functions {
+        resolver.scopes.push(Vec::new());
+        for param in &mut function.params {
+            param.id = resolver.declare(&param.name);
+        }
+        resolver.block(&mut function.body);
+        resolver.scopes.pop();
+    }
+    resolver.resolution
+}
+
+struct Resolver<'d> {
+    functions: Vec<String>,
+    scopes: Vec<Vec<(String, usize)>>,
+    resolution: Resolution,
+    diagnostics: &'d mut Vec<Diagnostic>,
+}
+
+impl Resolver<'_> {
+    fn declare(&mut self, name: &Ident) -> usize {
+        let id = self.resolution.uses.len();
+        self.resolution.uses.push(Vec::new());
+        self.resolution.assigns.push(Vec::new());
+        self.scopes
+            .last_mut()
+            .unwrap()
+            .push((name.name.clone(), id));
+        id
+    }
+
+    fn lookup(&mut self, name: &Ident) -> Option<usize> {
+        let found = self
+            .scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(declared, _)| *declared == name.name)
+            .map(|&(_, id)| id);
+        if found.is_none() {
+            self.diagnostics.push(Diagnostic::new(
+                "E0425",
+                format!("cannot find value `{}` in this scope", name.name),
+                name.span,
+                "not found in this scope",
+            ));
+        }
+        found
+    }
+
+    fn block(&mut self, block: &mut Block) {
+        self.scopes.push(Vec::new());
+        for stmt in &mut block.stmts {
+            match stmt {
+                Stmt::Let { name, init, id, .. } => {
+                    if let Some(init) = init {
+                        self.expr(init);
+                    }
+                    *id = self.declare(name);
+                }
+                Stmt::Assign { name, value, id } => {
+                    self.expr(value);
+                    *id = self.lookup(name);
+                    if let Some(id) = *id {
+                        self.resolution.assigns[id].push(name.span.start);
+                    }
+                }
+                Stmt::Expr(expr) => self.expr(expr),
+            }
+        }
+        if let Some(tail) = &mut block.tail {
+            self.expr(tail);
+        }
+        self.scopes.pop();
+    }
+
+    fn expr(&mut self, expr: &mut Expr) {
+        match &mut expr.kind {
+            ExprKind::Int | ExprKind::Char | ExprKind::Str => {}
+            ExprKind::Var(name, id) => {
+                *id = self.lookup(name);
+                if let Some(id) = *id {
+                    self.resolution.uses[id].push(name.span);
+                }
+            }
+            ExprKind::Ref { expr, .. } => self.expr(expr),
+            ExprKind::Call { path, args } => {
+                let builtin = path.name.starts_with("String::");
+                if !builtin && !self.functions.contains(&path.name) {
+                    self.diagnostics.push(Diagnostic::new(
+                        "E0425",
+                        format!("cannot find function `{}` in this scope", path.name),
+                        path.span,
+                        "not found in this scope",
+                    ));
+                }
+                args.iter_mut().for_each(|arg| self.expr(arg));
+            }
+            ExprKind::Method { receiver, args, .. } => {
+                self.expr(receiver);
+                args.iter_mut().for_each(|arg| self.expr(arg));
+            }
+            ExprKind::Macro { args, .. } => args.iter_mut().for_each(|arg| self.expr(arg)),
+            ExprKind::Block(block) => self.block(block),
+        }
+    }
+}
+
+// What a value is, as far as moves and borrows go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ty {
+    /// Numbers and the like, copied.
+    Copy,
+    /// `&T`, copied, and it keeps its borrows alive.
+    Shared,
+    /// `&mut T`, moved, and passing it to a function only reborrows it.
+    Mutable,
+    /// `String`, moved.
+    Owned,
+    /// Not known yet, a `let x;` before it is assigned.
+    Unknown,
+}
+
+impl Ty {
+    fn of(ty: &Type) -> Ty {
+        match ty {
+            Type::Ref { mutable: true, .. } => Ty::Mutable,
+            Type::Ref { .. } => Ty::Shared,
+            Type::Named(name) if name == "String" || name == "Vec" => Ty::Owned,
+            Type::Named(_) => Ty::Copy,
+        }
+    }
+
+    fn moves(self) -> bool {
+        matches!(self, Ty::Owned | Ty::Mutable)
+    }
+}
+
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Named(name) => name.clone(),
+        Type::Ref { mutable, inner, .. } => format!(
+            "&{}{}",
+            if *mutable { "mut " } else { "" },
+            type_name(inner)
+        ),
+    }
+}
+
+// A value being computed: what it is, and the borrows it carries.
+struct Value {
+    ty: Ty,
+    type_name: String,
+    loans: Vec<usize>,
+}
+
+impl Value {
+    fn plain(ty: Ty, type_name: &str) -> Self {
+        Value {
+            ty,
+            type_name: type_name.to_string(),
+            loans: Vec::new(),
+        }
+    }
+}
+
+struct Binding {
+    name: String,
+    ty: Ty,
+    type_name: String,
+    mutable: bool,
+    param: bool,
+    decl: Span,
+    initialized: Option<Span>,
+    moved: Option<Span>,
+    /// The closing brace of its block, once it is past.
+    dropped: Option<Span>,
+}
+
+struct Loan {
+    place: usize,
+    mutable: bool,
+    span: Span,
+    /// The bindings holding the reference, none while it is a temporary.
+    holders: Vec<usize>,
+    ended: bool,
+}
+
+// How a variable is used.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// By value: moved, or copied.
+    Move,
+    /// Read through a reference, like `println!` and `s.len()` do.
+    Borrow,
+    /// A `&mut` passed to a function, which reborrows it instead of moving it.
+    Reborrow,
+}
+
+struct Checker<'p> {
+    functions: HashMap<&'p str, &'p Function>,
+    resolution: &'p Resolution,
+    bindings: HashMap<usize, Binding>,
+    loans: Vec<Loan>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'p> Checker<'p> {
+    fn new(program: &'p Program, resolution: &'p Resolution) -> Self {
+        Checker {
+            functions: program
+                .functions
+                .iter()
+                .map(|function| (function.name.name.as_str(), function))
+                .collect(),
+            resolution,
+            bindings: HashMap::new(),
+            loans: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn function(&mut self, function: &Function) {
+        for param in &function.params {
+            self.bindings.insert(
+                param.id,
+                Binding {
+                    name: param.name.name.clone(),
+                    ty: Ty::of(&param.ty),
+                    type_name: type_name(&param.ty),
+                    mutable: param.mutable,
+                    param: true,
+                    decl: param.name.span,
+                    initialized: Some(param.name.span),
+                    moved: None,
+                    dropped: None,
+                },
+            );
+        }
+        let value = self.block(&function.body);
+        // E0515: the function is over, every local is gone
+        if matches!(function.ret, Some(Type::Ref { .. })) {
+            for &loan in &value.loans {
+                let loan = &self.loans[loan];
+                let place = &self.bindings[&loan.place];
+                if !place.param {
+                    self.diagnostics.push(Diagnostic::new(
+                        "E0515",
+                        format!("cannot return reference to local variable `{}`", place.name),
+                        loan.span,
+                        "returns a reference to data owned by the current function",
+                    ));
+                }
+            }
+        }
+    }
+
+    fn block(&mut self, block: &Block) -> Value {
+        let mut declared = Vec::new();
+        for stmt in &block.stmts {
+            match stmt {
+                Stmt::Let {
+                    name,
+                    mutable,
+                    ty,
+                    init,
+                    id,
+                } => {
+                    let value = init.as_ref().map(|init| self.operand(init, Mode::Move));
+                    let (binding_ty, binding_type_name) = match (ty, &value) {
+                        (Some(ty), _) => (Ty::of(ty), type_name(ty)),
+                        (None, Some(value)) => (value.ty, value.type_name.clone()),
+                        (None, None) => (Ty::Unknown, String::from("_")),
+                    };
+                    self.bindings.insert(
+                        *id,
+                        Binding {
+                            name: name.name.clone(),
+                            ty: binding_ty,
+                            type_name: binding_type_name,
+                            mutable: *mutable,
+                            param: false,
+                            decl: name.span,
+                            initialized: init.as_ref().map(|init| init.span),
+                            moved: None,
+                            dropped: None,
+                        },
+                    );
+                    declared.push(*id);
+                    if let Some(value) = value {
+                        self.hold(*id, &value.loans);
+                    }
+                }
+                Stmt::Assign { name, value, id } => {
+                    let value = self.operand(value, Mode::Move);
+                    if let Some(id) = *id {
+                        self.assign(id, name.span, value);
+                    }
+                }
+                Stmt::Expr(expr) => {
+                    self.operand(expr, Mode::Move);
+                }
+            }
+            self.end_temporaries(&[]);
+        }
+        let value = match &block.tail {
+            Some(tail) => self.operand(tail, Mode::Move),
+            None => Value::plain(Ty::Copy, "()"),
+        };
+        // the temporaries of the tail are dropped before the bindings (edition 2024), but the
+        // borrows the block gives back live on
+        self.end_temporaries(&value.loans);
+
+        // the bindings of the block are dropped at its closing brace
+        let end = Span::new(block.span.end - 1, block.span.end);
+        for &id in &declared {
+            self.bindings.get_mut(&id).unwrap().dropped = Some(end);
+        }
+        for loan in 0..self.loans.len() {
+            if declared.contains(&self.loans[loan].place) && !value.loans.contains(&loan) {
+                self.check_outlives(loan, end.start);
+            }
+        }
+        value
+    }
+
+    // A binding starts holding the references of `loans`: they last as long as it is used.
+    fn hold(&mut self, id: usize, loans: &[usize]) {
+        for &loan in loans {
+            self.loans[loan].holders.push(id);
+            self.check_outlives(loan, self.bindings[&id].decl.start);
+        }
+    }
+
+    // E0597: the borrowed binding was dropped, but a reference to it is still used after `at`.
+    fn check_outlives(&mut self, loan: usize, at: usize) {
+        let place = self.loans[loan].place;
+        let Some(dropped) = self.bindings[&place].dropped else {
+            return;
+        };
+        if let Some(later) = self.later_use(loan, at) {
+            let name = &self.bindings[&place].name;
+            let diagnostic = Diagnostic::new(
+                "E0597",
+                format!("`{name}` does not live long enough"),
+                self.loans[loan].span,
+                "borrowed value does not live long enough",
+            )
+            .label(
+                dropped,
+                format!("`{name}` dropped here while still borrowed"),
+            )
+            .label(later, "borrow later used here");
+            self.diagnostics.push(diagnostic);
+            self.loans[loan].ended = true;
+        }
+    }
+
+    fn assign(&mut self, id: usize, span: Span, value: Value) {
+        let binding = &self.bindings[&id];
+        if let Some(first) = binding.initialized
+            && !binding.mutable
+        {
+            let diagnostic = Diagnostic::new(
+                "E0384",
+                format!(
+                    "cannot assign twice to immutable variable `{}`",
+                    binding.name
+                ),
+                span,
+                "cannot assign twice to immutable variable",
+            )
+            .label(first, format!("first assignment to `{}`", binding.name));
+            self.diagnostics.push(diagnostic);
+        }
+        if let Some((loan, later)) = self.conflict(id, true, span.start) {
+            let name = &self.bindings[&id].name;
+            let diagnostic = Diagnostic::new(
+                "E0506",
+                format!("cannot assign to `{name}` because it is borrowed"),
+                span,
+                &format!("`{name}` is assigned to here but it was already borrowed"),
+            )
+            .label(self.loans[loan].span, format!("`{name}` is borrowed here"))
+            .label(later, "borrow later used here");
+            self.diagnostics.push(diagnostic);
+        }
+        // the old references it held are gone
+        for loan in &mut self.loans {
+            loan.holders.retain(|&holder| holder != id);
+        }
+        let binding = self.bindings.get_mut(&id).unwrap();
+        binding.initialized = Some(span);
+        binding.moved = None;
+        if binding.ty == Ty::Unknown {
+            binding.ty = value.ty;
+            binding.type_name = value.type_name.clone();
+        }
+        self.hold(id, &value.loans);
+    }
+
+    // Borrows no binding holds end with their statement, but for the ones in `keep`.
+    fn end_temporaries(&mut self, keep: &[usize]) {
+        for (i, loan) in self.loans.iter_mut().enumerate() {
+            if loan.holders.is_empty() && !keep.contains(&i) {
+                loan.ended = true;
+            }
+        }
+    }
+
+    // The first use after `at` of a reference holding `loan`, if the loan is still alive. A
+    // temporary one is alive until the end of its statement.
+    fn later_use(&self, loan: usize, at: usize) -> Option<Span> {
+        let loan = &self.loans[loan];
+        if loan.ended {
+            return None;
+        }
+        if loan.holders.is_empty() {
+            return Some(loan.span);
+        }
+        loan.holders
+            .iter()
+            .filter_map(|&holder| {
+                let next = self.resolution.uses[holder]
+                    .iter()
+                    .find(|span| span.start > at)?;
+                // an assignment in between gives the holder another reference
+                let reassigned = self.resolution.assigns[holder]
+                    .iter()
+                    .any(|&assign| assign > at && assign < next.start);
+                (!reassigned).then_some(*next)
+            })
+            .min_by_key(|span| span.start)
+    }
+
+    // A borrow of `place` alive at `at` that a new `mutable` (or shared) access conflicts with,
+    // and where it is used later.
+    fn conflict(&self, place: usize, mutable: bool, at: usize) -> Option<(usize, Span)> {
+        (0..self.loans.len())
+            .filter(|&loan| self.loans[loan].place == place)
+            .filter(|&loan| mutable || self.loans[loan].mutable)
+            .find_map(|loan| Some((loan, self.later_use(loan, at)?)))
+    }
+
+    fn operand(&mut self, expr: &Expr, mode: Mode) -> Value {
+        match &expr.kind {
+            ExprKind::Int => Value::plain(Ty::Copy, "i32"),
+            ExprKind::Char => Value::plain(Ty::Copy, "char"),
+            ExprKind::Str => Value::plain(Ty::Shared, "&str"),
+            ExprKind::Var(name, id) => match id {
+                Some(id) => self.var(*id, name.span, mode),
+                None => Value::plain(Ty::Unknown, "_"),
+            },
+            ExprKind::Ref {
+                mutable,
+                expr: inner,
+            } => {
+                if let ExprKind::Var(_, Some(id)) = &inner.kind {
+                    let loan = self.borrow(*id, *mutable, expr.span);
+                    let binding = &self.bindings[id];
+                    let type_name = format!(
+                        "&{}{}",
+                        if *mutable { "mut " } else { "" },
+                        binding.type_name
+                    );
+                    let ty = if *mutable { Ty::Mutable } else { Ty::Shared };
+                    Value {
+                        ty,
+                        type_name,
+                        loans: loan.into_iter().collect(),
+                    }
+                } else {
+                    // a reference to a temporary, which lives as long as the reference
+                    let value = self.operand(inner, Mode::Move);
+                    Value {
+                        ty: if *mutable { Ty::Mutable } else { Ty::Shared },
+                        type_name: format!("&{}", value.type_name),
+                        loans: value.loans,
+                    }
+                }
+            }
+            ExprKind::Call { path, args } => self.call(path, args),
+            ExprKind::Method {
+                receiver,
+                method,
+                args,
+            } => {
+                for arg in args {
+                    self.operand(arg, Mode::Move);
+                }
+                self.method(receiver, method)
+            }
+            ExprKind::Macro { args, .. } => {
+                for arg in args {
+                    self.operand(arg, Mode::Borrow);
+                }
+                Value::plain(Ty::Copy, "()")
+            }
+            ExprKind::Block(block) => self.block(block),
+        }
+    }
+
+    fn call(&mut self, path: &Ident, args: &[Expr]) -> Value {
+        let Some(&function) = self.functions.get(path.name.as_str()) else {
+            // `String::from` and `String::new`
+            for arg in args {
+                self.operand(arg, Mode::Borrow);
+            }
+            return Value::plain(Ty::Owned, "String");
+        };
+        let mut loans = Vec::new();
+        for (i, arg) in args.iter().enumerate() {
+            let param = function.params.get(i).map(|param| Ty::of(&param.ty));
+            let mode = if param == Some(Ty::Mutable) {
+                Mode::Reborrow
+            } else {
+                Mode::Move
+            };
+            loans.extend(self.operand(arg, mode).loans);
+        }
+        match &function.ret {
+            // the result borrows from the arguments (the lifetime elision rules)
+            Some(ret @ Type::Ref { .. }) => Value {
+                ty: Ty::of(ret),
+                type_name: type_name(ret),
+                loans,
+            },
+            Some(ret) => Value::plain(Ty::of(ret), &type_name(ret)),
+            None => Value::plain(Ty::Copy, "()"),
+        }
+    }
+
+    fn method(&mut self, receiver: &Expr, method: &Ident) -> Value {
+        const MUTATING: [&str; 6] = ["push_str", "push", "clear", "truncate", "insert", "pop"];
+        let mutating = MUTATING.contains(&method.name.as_str());
+        let ExprKind::Var(_, Some(id)) = receiver.kind else {
+            self.operand(receiver, Mode::Borrow);
+            return Value::plain(Ty::Copy, "_");
+        };
+        let ty = self.bindings[&id].ty;
+        let loans = match ty {
+            // through a reference, the reference is used
+            Ty::Shared | Ty::Mutable => {
+                if mutating && ty == Ty::Shared {
+                    let binding = &self.bindings[&id];
+                    self.diagnostics.push(Diagnostic::new(
+                        "E0596",
+                        format!(
+                            "cannot borrow `*{}` as mutable, as it is behind a `&` reference",
+                            binding.name
+                        ),
+                        receiver.span,
+                        &format!(
+                            "`{}` is a `&` reference, so the data it refers to cannot be borrowed as mutable",
+                            binding.name
+                        ),
+                    ));
+                }
+                self.var(id, receiver.span, Mode::Borrow).loans
+            }
+            // on the value itself, a temporary borrow of it
+            _ => self
+                .borrow(id, mutating, receiver.span)
+                .into_iter()
+                .collect(),
+        };
+        match method.name.as_str() {
+            "clone" | "to_string" => Value::plain(Ty::Owned, "String"),
+            "as_str" => Value {
+                ty: Ty::Shared,
+                type_name: String::from("&str"),
+                loans,
+            },
+            _ => Value::plain(Ty::Copy, "usize"),
+        }
+    }
+
+    // A variable used as a value.
+    fn var(&mut self, id: usize, span: Span, mode: Mode) -> Value {
+        let binding = &self.bindings[&id];
+        let (name, ty, type_name) = (binding.name.clone(), binding.ty, binding.type_name.clone());
+        let held: Vec<usize> = (0..self.loans.len())
+            .filter(|&loan| self.loans[loan].holders.contains(&id))
+            .collect();
+        if !self.initialized(id, span) || self.moved(id, span, mode == Mode::Borrow) {
+            return Value {
+                ty,
+                type_name,
+                loans: held,
+            };
+        }
+        let moves = mode == Mode::Move && ty.moves();
+        if moves {
+            if let Some((loan, later)) = self.conflict(id, true, span.start) {
+                let diagnostic = Diagnostic::new(
+                    "E0505",
+                    format!("cannot move out of `{name}` because it is borrowed"),
+                    span,
+                    &format!("move out of `{name}` occurs here"),
+                )
+                .label(
+                    self.loans[loan].span,
+                    format!("borrow of `{name}` occurs here"),
+                )
+                .label(later, "borrow later used here");
+                self.diagnostics.push(diagnostic);
+            }
+            self.bindings.get_mut(&id).unwrap().moved = Some(span);
+        } else if let Some((loan, later)) = self.conflict(id, false, span.start) {
+            // reading while a `&mut` is alive
+            let diagnostic = if mode == Mode::Borrow {
+                Diagnostic::new(
+                    "E0502",
+                    format!(
+                        "cannot borrow `{name}` as immutable because it is also borrowed as mutable"
+                    ),
+                    span,
+                    "immutable borrow occurs here",
+                )
+                .label(self.loans[loan].span, "mutable borrow occurs here")
+                .label(later, "mutable borrow later used here")
+            } else {
+                Diagnostic::new(
+                    "E0503",
+                    format!("cannot use `{name}` because it was mutably borrowed"),
+                    span,
+                    &format!("use of borrowed `{name}`"),
+                )
+                .label(self.loans[loan].span, format!("`{name}` is borrowed here"))
+                .label(later, "borrow later used here")
+            };
+            self.diagnostics.push(diagnostic);
+        }
+        Value {
+            ty,
+            type_name,
+            loans: held,
+        }
+    }
+
+    // `&place` or `&mut place`: the new borrow, unless it was refused.
+    fn borrow(&mut self, place: usize, mutable: bool, span: Span) -> Option<usize> {
+        if !self.initialized(place, span) || self.moved(place, span, true) {
+            return None;
+        }
+        let binding = &self.bindings[&place];
+        let name = binding.name.clone();
+        if mutable && !binding.mutable && binding.ty != Ty::Mutable {
+            let diagnostic = Diagnostic::new(
+                "E0596",
+                format!("cannot borrow `{name}` as mutable, as it is not declared as mutable"),
+                span,
+                "cannot borrow as mutable",
+            );
+            self.diagnostics.push(diagnostic);
+            return None;
+        }
+        if let Some((loan, later)) = self.conflict(place, mutable, span.start) {
+            let first = &self.loans[loan];
+            let diagnostic = match (first.mutable, mutable) {
+                (true, true) => Diagnostic::new(
+                    "E0499",
+                    format!("cannot borrow `{name}` as mutable more than once at a time"),
+                    span,
+                    "second mutable borrow occurs here",
+                )
+                .label(first.span, "first mutable borrow occurs here")
+                .label(later, "first borrow later used here"),
+                _ => {
+                    let (kind, other) = if mutable {
+                        ("mutable", "immutable")
+                    } else {
+                        ("immutable", "mutable")
+                    };
+                    Diagnostic::new(
+                        "E0502",
+                        format!(
+                            "cannot borrow `{name}` as {kind} because it is also borrowed as {other}"
+                        ),
+                        span,
+                        &format!("{kind} borrow occurs here"),
+                    )
+                    .label(first.span, format!("{other} borrow occurs here"))
+                    .label(later, format!("{other} borrow later used here"))
+                }
+            };
+            self.diagnostics.push(diagnostic);
+            return None;
+        }
+        self.loans.push(Loan {
+            place,
+            mutable,
+            span,
+            holders: Vec::new(),
+            ended: false,
+        });
+        Some(self.loans.len() - 1)
+    }
+
+    // E0381, false when `id` has no value yet.
+    fn initialized(&mut self, id: usize, span: Span) -> bool {
+        let binding = &self.bindings[&id];
+        if binding.initialized.is_some() {
+            return true;
+        }
+        let diagnostic = Diagnostic::new(
+            "E0381",
+            format!("used binding `{}` isn't initialized", binding.name),
+            span,
+            &format!("`{}` used here but it isn't initialized", binding.name),
+        )
+        .label(binding.decl, "binding declared here but left uninitialized");
+        self.diagnostics.push(diagnostic);
+        false
+    }
+
+    // E0382, true when `id` moved before `span`.
+    fn moved(&mut self, id: usize, span: Span, borrowed: bool) -> bool {
+        let binding = &self.bindings[&id];
+        let Some(moved) = binding.moved else {
+            return false;
+        };
+        let (what, label) = if borrowed {
+            ("borrow", "value borrowed here after move")
+        } else {
+            ("use", "value used here after move")
+        };
+        let diagnostic = Diagnostic::new(
+            "E0382",
+            format!("{what} of moved value: `{}`", binding.name),
+            span,
+            label,
+        )
+        .label(moved, "value moved here")
+        .label(
+            binding.decl,
+            format!(
+                "move occurs because `{}` has type `{}`, which does not implement the `Copy` trait",
+                binding.name, binding.type_name
+            ),
+        );
+        self.diagnostics.push(diagnostic);
+        true
+    }
+}
+
+/// The diagnostics the way rustc prints them: the error, then the lines of its labels with the
+/// spans underlined, `^` for the primary label and `-` for the others.
+pub fn render(path: &str, source: &str, diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::new();
+    for diagnostic in diagnostics {
+        match diagnostic.code {
+            Some(code) => {
+                let _ = writeln!(out, "error[{code}]: {}", diagnostic.message);
+            }
+            None if diagnostic.unsupported => {
+                let _ = writeln!(out, "unsupported: {}", diagnostic.message);
+            }
+            None => {
+                let _ = writeln!(out, "error: {}", diagnostic.message);
+            }
+        }
+        let mut labels: Vec<(usize, usize, usize, &Label, bool)> = diagnostic
+            .labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                let (line, column) = line_column(source, label.span.start);
+                let width = source[label.span.start..label.span.end.min(source.len())]
+                    .chars()
+                    .count()
+                    .max(1);
+                (line, column, width, label, i == 0)
+            })
+            .collect();
+        let (line, column, ..) = labels[0];
+        let gutter = labels.iter().map(|l| l.0).max().unwrap().to_string().len();
+        let _ = writeln!(out, "{:gutter$}--> {path}:{line}:{column}", "");
+        let _ = writeln!(out, "{:gutter$} |", "");
+        labels.sort_by_key(|&(line, column, ..)| (line, column));
+        let mut shown = None;
+        for (line, column, width, label, primary) in labels {
+            if shown != Some(line) {
+                let text = source.lines().nth(line - 1).unwrap_or("");
+                let _ = writeln!(out, "{line:>gutter$} | {text}");
+                shown = Some(line);
+            }
+            let mark = if primary { "^" } else { "-" };
+            let underline = format!("{:pad$}{}", "", mark.repeat(width), pad = column - 1);
+            let _ = writeln!(
+                out,
+                "{}",
+                format!("{:gutter$} | {underline} {}", "", label.message).trim_end()
+            );
+        }
+        out.push('\n');
+    }
+    let errors = diagnostics.iter().filter(|d| !d.unsupported).count();
+    if errors < diagnostics.len() {
+        out.push_str("note: the program may well compile, rustc can tell\n");
+    }
+    match errors {
+        0 => {}
+        1 => out.push_str("error: aborting due to 1 previous error\n"),
+        _ => {
+            let _ = writeln!(out, "error: aborting due to {errors} previous errors");
+        }
+    }
+    out
+}
+
+// 1-based line and column (in characters) of a byte offset.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
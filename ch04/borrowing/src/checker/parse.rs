@@ -0,0 +1,645 @@
+//! The parser of the language [`check`](super::check) reads: the part of Rust the ownership
+//! chapters use.
+//!
+//! ```text
+//! program  = function*
+//! function = "fn" name generics? "(" params ")" ("->" type)? block
+//! type     = "&" lifetime? "mut"? type | name
+//! block    = "{" statement* expr? "}"
+//! statement = "let" "mut"? name (":" type)? ("=" expr)? ";" | name "=" expr ";" | expr ";"?
+//! expr     = "&" "mut"? expr | literal | path "(" args ")" | name "!" "(" args ")"
+//!          | expr "." name "(" args ")" | name | block
+//! ```
+//!
+//! Rust that isn't in the grammar, like a keyword, an operator or a `[`, is a [`ParseError`] that
+//! is `unsupported` instead of a syntax error.
+
+use super::Span;
+
+/// A name and where it is written.
+#[derive(Debug, Clone)]
+pub struct Ident {
+    pub name: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct Program {
+    pub functions: Vec<Function>,
+}
+
+#[derive(Debug)]
+pub struct Function {
+    pub name: Ident,
+    pub params: Vec<Param>,
+    pub ret: Option<Type>,
+    pub body: Block,
+}
+
+#[derive(Debug)]
+pub struct Param {
+    pub name: Ident,
+    pub mutable: bool,
+    pub ty: Type,
+    /// Filled in by the resolver.
+    pub id: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum Type {
+    Named(String),
+    Ref {
+        mutable: bool,
+        lifetime: Option<String>,
+        inner: Box<Type>,
+        /// The `&`.
+        span: Span,
+    },
+}
+
+#[derive(Debug)]
+pub struct Block {
+    pub stmts: Vec<Stmt>,
+    pub tail: Option<Box<Expr>>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum Stmt {
+    Let {
+        name: Ident,
+        mutable: bool,
+        ty: Option<Type>,
+        init: Option<Expr>,
+        /// Filled in by the resolver.
+        id: usize,
+    },
+    Assign {
+        name: Ident,
+        value: Expr,
+        /// Filled in by the resolver.
+        id: Option<usize>,
+    },
+    Expr(Expr),
+}
+
+#[derive(Debug)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ExprKind {
+    Int,
+    Char,
+    Str,
+    /// A variable, with the binding the resolver found for it.
+    Var(Ident, Option<usize>),
+    Ref {
+        mutable: bool,
+        expr: Box<Expr>,
+    },
+    /// `f(args)` or `String::from(args)`.
+    Call {
+        path: Ident,
+        args: Vec<Expr>,
+    },
+    Method {
+        receiver: Box<Expr>,
+        method: Ident,
+        args: Vec<Expr>,
+    },
+    /// `println!("{} {s}", a)`: the variables of the format string come first, as `Var`s.
+    Macro {
+        args: Vec<Expr>,
+    },
+    Block(Block),
+}
+
+/// A syntax error, or Rust this parser doesn't read.
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Span,
+    /// The source is Rust outside the subset, like `a + b` or `vec![1]`, not a mistake.
+    pub unsupported: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Lifetime(String),
+    Int,
+    Char,
+    Str(String),
+    Punct(&'static str),
+    Eof,
+}
+
+const PUNCTS: [&str; 16] = [
+    "->", "::", "(", ")", "{", "}", "<", ">", ";", ",", ":", "=", "&", ".", "!", "+",
+];
+
+// Characters of Rust's own tokens that the subset has no use for: `[`, `*`, `-1`, `a / b`, ...
+const RUST_ONLY: &str = "[]*-/%|^?#@$~";
+
+// Rust's keywords, but for the `fn`, `let`, `mut` and `pub` of the subset.
+const KEYWORDS: [&str; 33] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "for", "if", "impl", "in", "loop", "match", "mod", "move", "ref", "return", "self",
+    "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "while",
+];
+
+fn lex(source: &str) -> Result<Vec<(Tok, Span)>, ParseError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        if source[i..].starts_with("//") {
+            i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            continue;
+        }
+        let tok = if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            Tok::Ident(source[start..i].to_string())
+        } else if c.is_ascii_digit() {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            Tok::Int
+        } else if c == b'"' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            if i >= bytes.len() {
+                return Err(ParseError {
+                    message: String::from("unterminated string"),
+                    span: Span::new(start, start + 1),
+                    unsupported: false,
+                });
+            }
+            i += 1;
+            Tok::Str(source[start + 1..i - 1].to_string())
+        } else if c == b'\'' && source[i + 1..].chars().nth(1) == Some('\'') {
+            i += 1 + source[i + 1..].chars().next().unwrap().len_utf8() + 1;
+            Tok::Char
+        } else if c == b'\'' {
+            i += 1;
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            Tok::Lifetime(source[start..i].to_string())
+        } else if let Some(punct) = PUNCTS.iter().find(|p| source[i..].starts_with(**p)) {
+            i += punct.len();
+            Tok::Punct(punct)
+        } else {
+            let c = source[i..].chars().next().unwrap();
+            let unsupported = RUST_ONLY.contains(c);
+            return Err(ParseError {
+                message: if unsupported {
+                    unsupported_message(&format!("`{c}`"))
+                } else {
+                    format!("unexpected character `{c}`")
+                },
+                span: Span::new(i, i + c.len_utf8()),
+                unsupported,
+            });
+        };
+        tokens.push((tok, Span::new(start, i)));
+    }
+    tokens.push((Tok::Eof, Span::new(source.len(), source.len())));
+    Ok(tokens)
+}
+
+/// Parses a whole program.
+pub fn parse(source: &str) -> Result<Program, ParseError> {
+    let mut parser = Parser {
+        tokens: lex(source)?,
+        pos: 0,
+    };
+    let mut functions = Vec::new();
+    while parser.peek() != &Tok::Eof {
+        functions.push(parser.function()?);
+    }
+    Ok(Program { functions })
+}
+
+struct Parser {
+    tokens: Vec<(Tok, Span)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Tok {
+        &self.tokens[self.pos].0
+    }
+
+    fn peek_at(&self, ahead: usize) -> &Tok {
+        &self.tokens[(self.pos + ahead).min(self.tokens.len() - 1)].0
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.pos].1
+    }
+
+    // The span of the token before the current one.
+    fn last_span(&self) -> Span {
+        self.tokens[self.pos.saturating_sub(1)].1
+    }
+
+    fn bump(&mut self) -> (Tok, Span) {
+        let token = self.tokens[self.pos].clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        if self.peek() == &Tok::Punct(PUNCTS.into_iter().find(|p| *p == punct).unwrap()) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Tok::Ident(name) if name == keyword) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<Span, ParseError> {
+        let span = self.span();
+        if self.eat(punct) {
+            Ok(span)
+        } else {
+            Err(self.error(&format!("expected `{punct}`")))
+        }
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        if let Some(what) = self.unsupported() {
+            return ParseError {
+                message: unsupported_message(&what),
+                span: self.span(),
+                unsupported: true,
+            };
+        }
+        let found = match self.peek() {
+            Tok::Ident(name) => format!("`{name}`"),
+            Tok::Lifetime(name) => format!("`{name}`"),
+            Tok::Int => String::from("a number"),
+            Tok::Char => String::from("a character"),
+            Tok::Str(_) => String::from("a string"),
+            Tok::Punct(punct) => format!("`{punct}`"),
+            Tok::Eof => String::from("the end of the file"),
+        };
+        ParseError {
+            message: format!("{expected}, found {found}"),
+            span: self.span(),
+            unsupported: false,
+        }
+    }
+
+    // The current token, when it is Rust the subset doesn't have rather than a mistake: a
+    // keyword like `if`, an operator like `+` or `==`, a generic type, a tuple field.
+    fn unsupported(&self) -> Option<String> {
+        let after_dot = self.pos > 0 && self.tokens[self.pos - 1].0 == Tok::Punct(".");
+        match self.peek() {
+            Tok::Ident(name) if KEYWORDS.contains(&name.as_str()) => Some(format!("`{name}`")),
+            Tok::Punct("=") if self.peek_at(1) == &Tok::Punct("=") => Some(String::from("`==`")),
+            Tok::Punct(punct @ ("+" | "<" | ">" | "!" | "::")) => Some(format!("`{punct}`")),
+            Tok::Int if after_dot => Some(String::from("a tuple field or a float")),
+            _ => None,
+        }
+    }
+
+    fn ident(&mut self) -> Result<Ident, ParseError> {
+        match self.peek().clone() {
+            // `if`, `return`, ... would be read as a variable
+            Tok::Ident(name) if !KEYWORDS.contains(&name.as_str()) => {
+                let (_, span) = self.bump();
+                Ok(Ident { name, span })
+            }
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    fn function(&mut self) -> Result<Function, ParseError> {
+        self.eat_keyword("pub");
+        if !self.eat_keyword("fn") {
+            return Err(self.error("expected `fn`"));
+        }
+        let name = self.ident()?;
+        // generics only name lifetimes, which the return type uses
+        if self.eat("<") {
+            while !self.eat(">") {
+                if self.peek() == &Tok::Eof {
+                    return Err(self.error("expected `>`"));
+                }
+                self.bump();
+            }
+        }
+        self.expect("(")?;
+        let mut params = Vec::new();
+        while !self.eat(")") {
+            let mutable = self.eat_keyword("mut");
+            let name = self.ident()?;
+            self.expect(":")?;
+            let ty = self.ty()?;
+            params.push(Param {
+                name,
+                mutable,
+                ty,
+                id: 0,
+            });
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        let ret = if self.eat("->") {
+            Some(self.ty()?)
+        } else {
+            None
+        };
+        let body = self.block()?;
+        Ok(Function {
+            name,
+            params,
+            ret,
+            body,
+        })
+    }
+
+    fn ty(&mut self) -> Result<Type, ParseError> {
+        let span = self.span();
+        if self.eat("&") {
+            let lifetime = match self.peek().clone() {
+                Tok::Lifetime(name) => {
+                    self.bump();
+                    Some(name)
+                }
+                _ => None,
+            };
+            let mutable = self.eat_keyword("mut");
+            let inner = Box::new(self.ty()?);
+            return Ok(Type::Ref {
+                mutable,
+                lifetime,
+                inner,
+                span,
+            });
+        }
+        Ok(Type::Named(self.ident()?.name))
+    }
+
+    fn block(&mut self) -> Result<Block, ParseError> {
+        let start = self.expect("{")?;
+        let mut stmts = Vec::new();
+        let mut tail = None;
+        loop {
+            if self.eat("}") {
+                break;
+            }
+            if self.eat(";") {
+                continue;
+            }
+            if self.eat_keyword("let") {
+                stmts.push(self.let_stmt()?);
+                continue;
+            }
+            if matches!(self.peek(), Tok::Ident(_)) && self.peek_at(1) == &Tok::Punct("=") {
+                let name = self.ident()?;
+                self.expect("=")?;
+                let value = self.expr()?;
+                self.expect(";")?;
+                stmts.push(Stmt::Assign {
+                    name,
+                    value,
+                    id: None,
+                });
+                continue;
+            }
+            let expr = self.expr()?;
+            if self.eat(";")
+                || matches!(expr.kind, ExprKind::Block(_)) && self.peek() != &Tok::Punct("}")
+            {
+                stmts.push(Stmt::Expr(expr));
+            } else if self.eat("}") {
+                tail = Some(Box::new(expr));
+                break;
+            } else {
+                return Err(self.error("expected `;` or `}`"));
+            }
+        }
+        Ok(Block {
+            stmts,
+            tail,
+            span: start.to(self.last_span()),
+        })
+    }
+
+    fn let_stmt(&mut self) -> Result<Stmt, ParseError> {
+        let mutable = self.eat_keyword("mut");
+        let name = self.ident()?;
+        let ty = if self.eat(":") {
+            Some(self.ty()?)
+        } else {
+            None
+        };
+        let init = if self.eat("=") {
+            Some(self.expr()?)
+        } else {
+            None
+        };
+        self.expect(";")?;
+        Ok(Stmt::Let {
+            name,
+            mutable,
+            ty,
+            init,
+            id: 0,
+        })
+    }
+
+    fn expr(&mut self) -> Result<Expr, ParseError> {
+        let start = self.span();
+        if self.eat("&") {
+            let mutable = self.eat_keyword("mut");
+            let expr = Box::new(self.expr()?);
+            let span = start.to(expr.span);
+            return Ok(Expr {
+                kind: ExprKind::Ref { mutable, expr },
+                span,
+            });
+        }
+        let mut expr = self.primary()?;
+        while self.eat(".") {
+            let method = self.ident()?;
+            let args = self.args()?;
+            expr = Expr {
+                span: expr.span.to(self.last_span()),
+                kind: ExprKind::Method {
+                    receiver: Box::new(expr),
+                    method,
+                    args,
+                },
+            };
+        }
+        Ok(expr)
+    }
+
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        let start = self.span();
+        match self.peek().clone() {
+            Tok::Int => {
+                self.bump();
+                Ok(Expr {
+                    kind: ExprKind::Int,
+                    span: start,
+                })
+            }
+            Tok::Char => {
+                self.bump();
+                Ok(Expr {
+                    kind: ExprKind::Char,
+                    span: start,
+                })
+            }
+            Tok::Str(_) => {
+                self.bump();
+                Ok(Expr {
+                    kind: ExprKind::Str,
+                    span: start,
+                })
+            }
+            Tok::Punct("{") => {
+                let block = self.block()?;
+                Ok(Expr {
+                    span: block.span,
+                    kind: ExprKind::Block(block),
+                })
+            }
+            Tok::Ident(_) => {
+                let mut path = self.ident()?;
+                while self.eat("::") {
+                    let next = self.ident()?;
+                    path.name = format!("{}::{}", path.name, next.name);
+                    path.span = path.span.to(next.span);
+                }
+                if self.peek() == &Tok::Punct("!") {
+                    self.bump();
+                    return self.macro_call(path);
+                }
+                if self.peek() == &Tok::Punct("(") {
+                    let args = self.args()?;
+                    return Ok(Expr {
+                        span: start.to(self.last_span()),
+                        kind: ExprKind::Call { path, args },
+                    });
+                }
+                Ok(Expr {
+                    span: path.span,
+                    kind: ExprKind::Var(path, None),
+                })
+            }
+            _ => Err(self.error("expected an expression")),
+        }
+    }
+
+    fn args(&mut self) -> Result<Vec<Expr>, ParseError> {
+        self.expect("(")?;
+        let mut args = Vec::new();
+        while !self.eat(")") {
+            args.push(self.expr()?);
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        Ok(args)
+    }
+
+    // `println!("{} {s}", a)`: the format string is read for the variables it names.
+    fn macro_call(&mut self, name: Ident) -> Result<Expr, ParseError> {
+        self.expect("(")?;
+        let mut args = Vec::new();
+        if let (Tok::Str(format), span) = (self.peek().clone(), self.span()) {
+            self.bump();
+            args.extend(captures(&format, span.start + 1));
+            if !self.eat(",") {
+                self.expect(")")?;
+                return Ok(Expr {
+                    span: name.span.to(self.last_span()),
+                    kind: ExprKind::Macro { args },
+                });
+            }
+        }
+        while !self.eat(")") {
+            args.push(self.expr()?);
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        Ok(Expr {
+            span: name.span.to(self.last_span()),
+            kind: ExprKind::Macro { args },
+        })
+    }
+}
+
+fn unsupported_message(what: &str) -> String {
+    format!("{what} isn't supported by this checker")
+}
+
+// The variables named in a format string, `{s1}` or `{s1:?}`, starting at `offset`.
+fn captures(format: &str, offset: usize) -> Vec<Expr> {
+    let mut vars = Vec::new();
+    let mut rest = format;
+    let mut at = offset;
+    while let Some(open) = rest.find('{') {
+        if rest[open..].starts_with("{{") {
+            at += open + 2;
+            rest = &rest[open + 2..];
+            continue;
+        }
+        let inner = &rest[open + 1..];
+        let len = inner
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(inner.len());
+        let name = &inner[..len];
+        if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            let span = Span::new(at + open + 1, at + open + 1 + len);
+            vars.push(Expr {
+                kind: ExprKind::Var(
+                    Ident {
+                        name: name.to_string(),
+                        span,
+                    },
+                    None,
+                ),
+                span,
+            });
+        }
+        at += open + 1 + len;
+        rest = &inner[len..];
+    }
+    vars
+}
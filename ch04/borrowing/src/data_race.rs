@@ -0,0 +1,165 @@
+//! The three conditions of a data race, with threads instead of references.
+//!
+//! The same workload, every thread adding one to a shared counter, runs three ways:
+//!
+//! - [`with_mutex`]: an `Arc<Mutex<usize>>`, a thread locks it for each increment, which is the
+//!   mechanism the third condition asks for.
+//! - [`with_atomic`]: an `Arc<AtomicUsize>`, each increment is one `fetch_add` the CPU won't
+//!   split.
+//! - `racy`, only with the `racy` feature: a plain `usize` all the threads read and write through
+//!   a raw pointer, which is all three conditions at once. Increments get lost, and it is
+//!   undefined behavior, which is why it takes `unsafe` and a feature flag to write it.
+//!
+//! Safe Rust can't write the third one: the counter would need to be `&mut` in every thread at
+//! once, the same rule that refuses `r1` and `r2` in `mutable_reference_demo`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::{Reporter, say};
+
+/// How many threads, each adding one how many times.
+#[derive(Debug, Clone, Copy)]
+pub struct Workload {
+    pub threads: usize,
+    pub increments: usize,
+}
+
+/// What a counter ended at, and how long the threads took to get it there.
+#[derive(Debug, Clone, Copy)]
+pub struct Run {
+    pub name: &'static str,
+    pub count: usize,
+    pub expected: usize,
+    pub elapsed: Duration,
+}
+
+impl Workload {
+    /// The count once every increment happened.
+    pub fn expected(&self) -> usize {
+        self.threads * self.increments
+    }
+}
+
+impl Run {
+    /// The increments another thread overwrote.
+    pub fn lost(&self) -> usize {
+        self.expected.saturating_sub(self.count)
+    }
+}
+
+/// Every way this build can run `workload`: the racy one too with the `racy` feature.
+pub fn runs(workload: Workload) -> Vec<Run> {
+    #[allow(unused_mut)]
+    let mut runs = vec![with_mutex(workload), with_atomic(workload)];
+    #[cfg(feature = "racy")]
+    runs.push(racy(workload));
+    runs
+}
+
+/// Each increment locks the counter, so only one thread touches it at a time.
+pub fn with_mutex(workload: Workload) -> Run {
+    let counter = Arc::new(Mutex::new(0));
+    timed("Arc<Mutex<usize>>", workload, || {
+        spawn_all(workload, &counter, |counter| {
+            *counter.lock().unwrap() += 1;
+        });
+        *counter.lock().unwrap()
+    })
+}
+
+/// Each increment is a single `fetch_add`, nothing to lock.
+pub fn with_atomic(workload: Workload) -> Run {
+    let counter = Arc::new(AtomicUsize::new(0));
+    timed("Arc<AtomicUsize>", workload, || {
+        spawn_all(workload, &counter, |counter| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        counter.load(Ordering::Relaxed)
+    })
+}
+
+/// Each increment reads the counter and writes it back plus one, while the other threads do
+/// the same: two threads that read the same count both write the same count plus one, and one
+/// increment is lost.
+///
+/// This is a data race, so undefined behavior: it shows what the borrow rules prevent, nothing
+/// more should be built on it.
+#[cfg(feature = "racy")]
+pub fn racy(workload: Workload) -> Run {
+    let counter = Arc::new(Racy(std::cell::UnsafeCell::new(0)));
+    timed("racy usize", workload, || {
+        spawn_all(workload, &counter, |counter| {
+            let count = counter.0.get();
+            // SAFETY: none, the other threads read and write `count` at the same time, on
+            // purpose. Volatile keeps the compiler from merging the increments into one add.
+            let read = unsafe { count.read_volatile() };
+            if read % YIELD_EVERY == 0 {
+                // on one core a thread is rarely switched out between the read and the write,
+                // this makes it happen now and then
+                thread::yield_now();
+            }
+            // SAFETY: as above.
+            unsafe { count.write_volatile(read + 1) };
+        });
+        // SAFETY: every thread has been joined, nothing else reads or writes it anymore.
+        unsafe { *counter.0.get() }
+    })
+}
+
+// How often `racy` lets another thread run between reading the counter and writing it back.
+#[cfg(feature = "racy")]
+const YIELD_EVERY: usize = 256;
+
+// A `usize` the threads can share without any synchronization, which safe Rust refuses.
+#[cfg(feature = "racy")]
+struct Racy(std::cell::UnsafeCell<usize>);
+
+// SAFETY: it isn't, that's the point of `racy`.
+#[cfg(feature = "racy")]
+unsafe impl Sync for Racy {}
+
+/// Reports what each run counted, and the increments it lost.
+pub fn report(out: &mut dyn Reporter, runs: &[Run]) {
+    for run in runs {
+        say!(
+            out,
+            "{:<17} counted {:>8} of {:>8}, {} lost",
+            run.name,
+            run.count,
+            run.expected,
+            run.lost()
+        );
+    }
+}
+
+// Runs `increment` `workload.increments` times on each of `workload.threads` threads, and
+// waits for all of them.
+fn spawn_all<C: Send + Sync + 'static>(workload: Workload, counter: &Arc<C>, increment: fn(&C)) {
+    let handles: Vec<_> = (0..workload.threads)
+        .map(|_| {
+            let counter = Arc::clone(counter);
+            thread::spawn(move || {
+                for _ in 0..workload.increments {
+                    increment(&counter);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+fn timed(name: &'static str, workload: Workload, count: impl FnOnce() -> usize) -> Run {
+    let start = Instant::now();
+    let count = count();
+    Run {
+        name,
+        count,
+        expected: workload.expected(),
+        elapsed: start.elapsed(),
+    }
+}
@@ -0,0 +1,235 @@
+//! Editing a `&mut String` in place, the way `mutable_reference` appends to one.
+//!
+//! Each function says when it can reallocate: the ones that only shrink the string or keep its
+//! length never do, and the ones that grow it only do when the result is longer than the
+//! capacity, like `push_str`. None of them allocates anything else.
+//!
+//! The ASCII case mappings are already in place in std, as `make_ascii_uppercase` and
+//! `make_ascii_lowercase`, and never reallocate either: [`make_uppercase`] and
+//! [`make_lowercase`] are the Unicode ones.
+//!
+//! Positions are in `char`s, not bytes, and widths in terminal columns, so `"Ιωάννης"` is 7 of
+//! each.
+
+use std::ops::Range;
+
+use crate::text_metrics;
+
+/// Removes the whitespace at both ends, moving the rest to the front. Never reallocates.
+pub fn trim(s: &mut String) {
+    let end = s.trim_end().len();
+    s.truncate(end);
+    let start = s.len() - s.trim_start().len();
+    s.drain(..start);
+}
+
+/// Maps every letter to its uppercase, like `str::to_uppercase`, which can make the string
+/// longer: `ß` is `SS`. Reallocates only when it gets longer than the capacity.
+pub fn make_uppercase(s: &mut String) {
+    map_chars(s, |_, c, _| MappedChars::Upper(c.to_uppercase()));
+}
+
+/// Maps every letter to its lowercase, like `str::to_lowercase`, with the final sigma: `ΟΔΟΣ`
+/// is `οδος`. Reallocates only when it gets longer than the capacity, which `İ` (`i̇`) does.
+pub fn make_lowercase(s: &mut String) {
+    map_chars(s, |before, c, after| {
+        // Σ at the end of a word is ς
+        let ends_word = |before: Option<char>, after: Option<char>| {
+            before.is_some_and(char::is_alphabetic) && !after.is_some_and(char::is_alphabetic)
+        };
+        if c == 'Σ' && ends_word(before, after) {
+            MappedChars::Char(Some('ς'))
+        } else {
+            MappedChars::Lower(c.to_lowercase())
+        }
+    });
+}
+
+/// Replaces every `from` with `to`, the matches `str::replace` finds, from left to right. Never
+/// reallocates when `to` is not longer than `from`, and otherwise only when the result is longer
+/// than the capacity.
+///
+/// # Panics
+///
+/// If `from` is empty.
+pub fn replace_all(s: &mut String, from: &str, to: &str) {
+    assert!(!from.is_empty(), "replace_all needs something to replace");
+    let len = s.len();
+    if to.len() <= from.len() {
+        // SAFETY: each match is replaced by `to` and the text between matches is kept, all of
+        // which are whole `char`s, so the bytes stay UTF-8.
+        let bytes = unsafe { s.as_mut_vec() };
+        // the result is written at `write`, behind `read`, so what is left to read is untouched
+        let (mut read, mut write) = (0, 0);
+        while read < len {
+            let next = find(&bytes[read..], from).map_or(len, |at| read + at);
+            bytes.copy_within(read..next, write);
+            write += next - read;
+            read = next;
+            if next < len {
+                bytes[write..write + to.len()].copy_from_slice(to.as_bytes());
+                write += to.len();
+                read += from.len();
+            }
+        }
+        bytes.truncate(write);
+    } else {
+        // found from the start, like the other branch: searched from the end, a `from` that
+        // overlaps itself, like "aa" in "aaa", would match elsewhere
+        let matches: Vec<usize> = s.match_indices(from).map(|(at, _)| at).collect();
+        let grown = len + matches.len() * (to.len() - from.len());
+        // SAFETY: as above; the bytes added at the end are all written over before this ends
+        let bytes = unsafe { s.as_mut_vec() };
+        bytes.resize(grown, 0);
+        // the result is written from the end, ahead of `read`, so what is left to read is too;
+        // the text before the first match is already where it goes
+        let (mut read, mut write) = (len, grown);
+        for &at in matches.iter().rev() {
+            let kept = read - (at + from.len());
+            bytes.copy_within(at + from.len()..read, write - kept);
+            write -= kept;
+            bytes[write - to.len()..write].copy_from_slice(to.as_bytes());
+            write -= to.len();
+            read = at;
+        }
+        debug_assert_eq!(read, write);
+    }
+}
+
+/// Replaces every run of whitespace with one space. Never reallocates.
+pub fn collapse_whitespace(s: &mut String) {
+    let len = s.len();
+    // SAFETY: whole `char`s are kept or dropped, and a whitespace `char` is replaced with a
+    // space, so the bytes stay UTF-8.
+    let bytes = unsafe { s.as_mut_vec() };
+    let (mut read, mut write) = (0, 0);
+    let mut in_whitespace = false;
+    while read < len {
+        // SAFETY: `read` is at a `char` boundary of the bytes not written over yet
+        let c = unsafe { std::str::from_utf8_unchecked(&bytes[read..]) }
+            .chars()
+            .next()
+            .unwrap();
+        if c.is_whitespace() {
+            if !in_whitespace {
+                bytes[write] = b' ';
+                write += 1;
+            }
+            in_whitespace = true;
+        } else {
+            bytes.copy_within(read..read + c.len_utf8(), write);
+            write += c.len_utf8();
+            in_whitespace = false;
+        }
+        read += c.len_utf8();
+    }
+    bytes.truncate(write);
+}
+
+/// Inserts `text` before the `char` at `at`, or at the end when `at` is the length in `char`s.
+/// Reallocates only when the string gets longer than the capacity.
+///
+/// # Panics
+///
+/// If the string has fewer than `at` `char`s.
+pub fn insert_at(s: &mut String, at: usize, text: &str) {
+    let at = byte_offset(s, at);
+    s.insert_str(at, text);
+}
+
+/// Removes the `char`s of `chars`. Never reallocates.
+///
+/// # Panics
+///
+/// If the range starts after it ends, or the string has fewer `char`s than it ends at.
+pub fn delete(s: &mut String, chars: Range<usize>) {
+    assert!(
+        chars.start <= chars.end,
+        "delete of {chars:?}, which ends before it starts"
+    );
+    let (start, end) = (byte_offset(s, chars.start), byte_offset(s, chars.end));
+    s.drain(start..end);
+}
+
+/// Breaks the lines longer than `width` columns at spaces, which become newlines. A word longer
+/// than `width` gets a line of its own and is left as long. Never reallocates.
+pub fn wrap(s: &mut String, width: usize) {
+    let mut line_start = 0;
+    // the last space of the line, where it can be broken
+    let mut space = None;
+    let mut at = 0;
+    while let Some(c) = s[at..].chars().next() {
+        if c == '\n' {
+            (line_start, space) = (at + 1, None);
+        } else if c == ' ' && text_metrics::width(&s[line_start..at]) > width {
+            // the line is too long before this space: break at the one before, if any
+            let end = space.unwrap_or(at);
+            s.replace_range(end..end + 1, "\n");
+            (line_start, space) = (end + 1, None);
+            continue;
+        } else if c == ' ' {
+            space = Some(at);
+        }
+        at += c.len_utf8();
+    }
+    if let Some(end) = space.filter(|_| text_metrics::width(&s[line_start..]) > width) {
+        s.replace_range(end..end + 1, "\n");
+    }
+}
+
+// What a `char` maps to: `ToUppercase` and `ToLowercase` are both up to three `char`s.
+enum MappedChars {
+    Upper(std::char::ToUppercase),
+    Lower(std::char::ToLowercase),
+    Char(Option<char>),
+}
+
+impl Iterator for MappedChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            MappedChars::Upper(chars) => chars.next(),
+            MappedChars::Lower(chars) => chars.next(),
+            MappedChars::Char(c) => c.take(),
+        }
+    }
+}
+
+// Replaces each `char` with what `map` maps it to, given the `char`s before (already mapped)
+// and after it. A mapping as long as the `char` is written over it, a longer or shorter one
+// moves the rest of the string.
+fn map_chars(s: &mut String, map: impl Fn(Option<char>, char, Option<char>) -> MappedChars) {
+    let mut before = None;
+    let mut at = 0;
+    while let Some(c) = s[at..].chars().next() {
+        let after = s[at + c.len_utf8()..].chars().next();
+        let mut buffer = [0; 12];
+        let mut len = 0;
+        for mapped in map(before, c, after) {
+            len += mapped.encode_utf8(&mut buffer[len..]).len();
+        }
+        // SAFETY: `buffer[..len]` is the UTF-8 of the mapped `char`s, written just above
+        let mapped = unsafe { std::str::from_utf8_unchecked(&buffer[..len]) };
+        if mapped.chars().ne([c]) {
+            s.replace_range(at..at + c.len_utf8(), mapped);
+        }
+        before = mapped.chars().next_back();
+        at += len;
+    }
+}
+
+// The offset in bytes of the `char` at `at`, or the length when `at` is the count of `char`s.
+fn byte_offset(s: &str, at: usize) -> usize {
+    s.char_indices()
+        .map(|(offset, _)| offset)
+        .chain([s.len()])
+        .nth(at)
+        .unwrap_or_else(|| panic!("char {at} is out of bounds of {s:?}"))
+}
+
+fn find(haystack: &[u8], needle: &str) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle.as_bytes())
+}
@@ -0,0 +1,339 @@
+//! A gap buffer: the text of an editor, with room at the cursor to type into.
+//!
+//! The text is kept in one buffer, with a gap of unused bytes where the cursor is:
+//!
+//! ```text
+//! "Hello, world!" with the cursor after "Hello,"
+//! H e l l o , _ _ _ _ _ _ _ _   w o r l d !
+//!             ^ gap           ^ rest
+//! ```
+//!
+//! Typing fills the gap from the left, deleting widens it, and moving the cursor moves the
+//! bytes between the old place and the new one across the gap. Editing where the last edit was,
+//! which is most of the time, copies nothing.
+//!
+//! The text is in two pieces around the gap, so a `&str` of a range that has the gap in it
+//! needs the gap moved out of the way first. [`view`](GapBuffer::view) does that, so it takes
+//! `&mut self`, and the `&str` it returns keeps the buffer borrowed, mutably, for as long as it
+//! is used: no edit can happen under it.
+//!
+//! ```compile_fail
+//! # use borrowing::gap_buffer::GapBuffer;
+//! let mut buffer = GapBuffer::from("foo\nbar\n");
+//! let first = buffer.line(0).unwrap();
+//! buffer.insert("baz\n"); // error[E0499]: `buffer` is still borrowed by `first`
+//! println!("{first}");
+//! ```
+//!
+//! Every edit can be undone, and redone until the next edit. Positions are in bytes, and must
+//! be on `char` boundaries.
+
+use std::fmt;
+use std::ops::Range;
+
+/// The bytes of gap a new buffer starts with.
+pub const GAP: usize = 64;
+
+/// Text with a cursor, undo and redo, and an index of where its lines start.
+#[derive(Debug, Clone)]
+pub struct GapBuffer {
+    // `bytes[..gap.start]` is the text before the gap, `bytes[gap.end..]` the text after it
+    bytes: Vec<u8>,
+    gap: Range<usize>,
+    cursor: usize,
+    // where each line starts, the first one at 0, kept up to date by every edit
+    line_starts: Vec<usize>,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+}
+
+// `removed` replaced by `inserted`, at `at`: undoing it is replacing `inserted` by `removed`.
+#[derive(Debug, Clone)]
+struct Edit {
+    at: usize,
+    removed: String,
+    inserted: String,
+}
+
+impl GapBuffer {
+    /// An empty buffer.
+    pub fn new() -> Self {
+        GapBuffer::from("")
+    }
+
+    /// The length of the text, in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len() - self.gap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Where the next [`insert`](GapBuffer::insert) goes.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to `at`.
+    ///
+    /// # Panics
+    ///
+    /// If `at` is past the end of the text or not on a `char` boundary.
+    pub fn set_cursor(&mut self, at: usize) {
+        self.check(at);
+        self.cursor = at;
+    }
+
+    /// Inserts `text` at the cursor, and moves the cursor after it.
+    pub fn insert(&mut self, text: &str) {
+        self.replace(self.cursor..self.cursor, text);
+    }
+
+    /// Deletes the `len` bytes after the cursor, and returns them.
+    ///
+    /// # Panics
+    ///
+    /// If they go past the end of the text or don't end on a `char` boundary.
+    pub fn delete(&mut self, len: usize) -> String {
+        let removed = self.text(self.cursor..self.cursor + len);
+        self.replace(self.cursor..self.cursor + len, "");
+        removed
+    }
+
+    /// Replaces `range` with `text`, as one edit, and moves the cursor after it.
+    ///
+    /// # Panics
+    ///
+    /// If `range` goes past the end of the text or doesn't start and end on `char` boundaries.
+    pub fn replace(&mut self, range: Range<usize>, text: &str) {
+        let edit = Edit {
+            at: range.start,
+            removed: self.text(range),
+            inserted: text.to_string(),
+        };
+        self.apply(&edit);
+        self.undo.push(edit);
+        self.redo.clear();
+    }
+
+    /// Undoes the last edit not undone yet, returns whether there was one. The cursor goes after
+    /// the text it puts back, as after any edit.
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        self.apply(&edit.inverse());
+        self.redo.push(edit);
+        true
+    }
+
+    /// Redoes the last edit undone, returns whether there was one.
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        self.apply(&edit);
+        self.undo.push(edit);
+        true
+    }
+
+    /// The text of `range`, in the two pieces before and after the gap, either of which can be
+    /// empty. Nothing moves, so this only needs `&self`.
+    ///
+    /// # Panics
+    ///
+    /// As [`replace`](GapBuffer::replace).
+    pub fn slices(&self, range: Range<usize>) -> (&str, &str) {
+        self.check(range.start);
+        self.check(range.end);
+        let split = self.gap.start;
+        let before = &self.bytes[range.start.min(split)..range.end.min(split)];
+        let after = &self.bytes
+            [range.start.max(split) + self.gap.len()..range.end.max(split) + self.gap.len()];
+        // SAFETY: the gap is always at a `char` boundary of the text, and `range` was checked to
+        // start and end on one, so both pieces are whole `char`s of UTF-8
+        unsafe {
+            (
+                std::str::from_utf8_unchecked(before),
+                std::str::from_utf8_unchecked(after),
+            )
+        }
+    }
+
+    /// The text of `range` as one `&str`, which moves the gap out of it when it is in it.
+    ///
+    /// # Panics
+    ///
+    /// As [`replace`](GapBuffer::replace).
+    pub fn view(&mut self, range: Range<usize>) -> &str {
+        if self.gap.start > range.start && self.gap.start < range.end {
+            self.move_gap(range.end);
+        }
+        let (before, after) = self.slices(range);
+        // one of them is empty now
+        if before.is_empty() { after } else { before }
+    }
+
+    /// The whole text as one `&str`, see [`view`](GapBuffer::view).
+    pub fn as_str(&mut self) -> &str {
+        self.view(0..self.len())
+    }
+
+    /// The number of lines, the last one counted even without a `\n` at its end.
+    pub fn line_count(&self) -> usize {
+        let len = self.len();
+        self.line_starts.partition_point(|&start| start < len)
+    }
+
+    /// The bytes of line `n`, counted from 0, with its `\n` if it has one.
+    pub fn line_range(&self, n: usize) -> Option<Range<usize>> {
+        if n >= self.line_count() {
+            return None;
+        }
+        let end = self.line_starts.get(n + 1).map_or(self.len(), |&next| next);
+        Some(self.line_starts[n]..end)
+    }
+
+    /// Line `n`, counted from 0, with its `\n` if it has one, see [`view`](GapBuffer::view).
+    pub fn line(&mut self, n: usize) -> Option<&str> {
+        let range = self.line_range(n)?;
+        Some(self.view(range))
+    }
+
+    /// The line that `at` is on, counted from 0.
+    pub fn line_of(&self, at: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= at) - 1
+    }
+
+    // Replaces `edit.removed` with `edit.inserted`, with the gap moved to the edit first.
+    fn apply(&mut self, edit: &Edit) {
+        let end = edit.at + edit.removed.len();
+        self.check(edit.at);
+        self.check(end);
+        self.move_gap(end);
+        // the removed text joins the gap, the inserted text fills it from the left
+        self.gap.start = edit.at;
+        if self.gap.len() < edit.inserted.len() {
+            self.grow(edit.inserted.len());
+        }
+        let start = self.gap.start;
+        self.bytes[start..start + edit.inserted.len()].copy_from_slice(edit.inserted.as_bytes());
+        self.gap.start += edit.inserted.len();
+        self.cursor = self.gap.start;
+        self.reindex(edit.at..end, &edit.inserted);
+    }
+
+    // Moves the gap to `at` of the text, which moves the bytes between it and `at`.
+    fn move_gap(&mut self, at: usize) {
+        let gap = self.gap.len();
+        if at < self.gap.start {
+            self.bytes.copy_within(at..self.gap.start, at + gap);
+        } else {
+            self.bytes
+                .copy_within(self.gap.end..at + gap, self.gap.start);
+        }
+        self.gap = at..at + gap;
+    }
+
+    // Makes the gap at least `needed` bytes, and twice the text, so growing costs O(1) per byte.
+    fn grow(&mut self, needed: usize) {
+        let gap = needed.max(self.len()).max(GAP);
+        let after = self.bytes.len() - self.gap.end;
+        self.bytes.resize(self.gap.start + gap + after, 0);
+        let new_end = self.bytes.len() - after;
+        self.bytes
+            .copy_within(self.gap.end..self.gap.end + after, new_end);
+        self.gap.end = new_end;
+    }
+
+    // Updates the starts of the lines after `removed` was replaced by `inserted`.
+    fn reindex(&mut self, removed: Range<usize>, inserted: &str) {
+        // the lines whose `\n` was removed are gone
+        let first = self
+            .line_starts
+            .partition_point(|&start| start <= removed.start);
+        let last = self
+            .line_starts
+            .partition_point(|&start| start <= removed.end);
+        let new_starts = inserted
+            .match_indices('\n')
+            .map(|(at, _)| removed.start + at + 1);
+        self.line_starts.splice(first..last, new_starts);
+        let moved = first + inserted.matches('\n').count();
+        for start in &mut self.line_starts[moved..] {
+            *start = *start - removed.len() + inserted.len();
+        }
+    }
+
+    fn text(&self, range: Range<usize>) -> String {
+        let (before, after) = self.slices(range);
+        [before, after].concat()
+    }
+
+    fn byte(&self, at: usize) -> u8 {
+        if at < self.gap.start {
+            self.bytes[at]
+        } else {
+            self.bytes[at + self.gap.len()]
+        }
+    }
+
+    fn check(&self, at: usize) {
+        assert!(
+            at <= self.len(),
+            "{at} is past the end of the text, {}",
+            self.len()
+        );
+        // a byte that isn't the first of a `char` is 0b10xx_xxxx
+        assert!(
+            at == self.len() || self.byte(at) & 0xC0 != 0x80,
+            "{at} is in the middle of a char"
+        );
+    }
+}
+
+impl Edit {
+    fn inverse(&self) -> Edit {
+        Edit {
+            at: self.at,
+            removed: self.inserted.clone(),
+            inserted: self.removed.clone(),
+        }
+    }
+}
+
+impl Default for GapBuffer {
+    fn default() -> Self {
+        GapBuffer::new()
+    }
+}
+
+impl From<&str> for GapBuffer {
+    /// `text`, with the cursor at the end and a gap of [`GAP`] bytes after it.
+    fn from(text: &str) -> Self {
+        let mut bytes = Vec::with_capacity(text.len() + GAP);
+        bytes.extend_from_slice(text.as_bytes());
+        bytes.resize(text.len() + GAP, 0);
+        let line_starts = [0]
+            .into_iter()
+            .chain(text.match_indices('\n').map(|(at, _)| at + 1))
+            .collect();
+        GapBuffer {
+            bytes,
+            gap: text.len()..text.len() + GAP,
+            cursor: text.len(),
+            line_starts,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for GapBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (before, after) = self.slices(0..self.len());
+        f.write_str(before)?;
+        f.write_str(after)
+    }
+}
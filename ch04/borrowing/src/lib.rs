@@ -0,0 +1,288 @@
+/* 
+A **reference** is like a **pointer** in that it’s an address we can follow to access the data stored at that address;
+ that data is owned by some other variable. 
+ 
+ --> Unlike a pointer, a reference is guaranteed to point to a valid value of a particular type for the life of that reference.
+
+These ampersands represent references, and they allow you to refer to some value without taking ownership of it.
+
+Note: The opposite of referencing by using & is dereferencing, which is accomplished with the dereference operator, *.
+We’ll see some uses of the dereference operator in Chapter 8 and discuss details of dereferencing in Chapter 15.
+
+When functions have references as parameters instead of the actual values, we won’t need to return the values in order to give back ownership, because we never had ownership.
+
+We call the action of creating a reference borrowing. As in real life, if a person owns something, you can borrow it from them. When you’re done, you have to give it back. You don’t own it.
+
+
+Note: 
+   This is more or less what is happening in python, references for objects (especially for mutable objects)
+   Python is pass-by-object-reference (or "pass-by-assignment").
+   It behaves like pass-by-reference for mutable objects, and like pass-by-value for immutable objects,
+   but technically, it's always passing references
+
+
+--> Just as variables are immutable by default, so are references. We’re not allowed to modify something we have a reference to.
+_
+
+
+
+
+**Mutable references** have one big restriction: if you have a mutable reference to a value, you can have no other references to that value.
+The benefit of having this restriction is that Rust can prevent **data races** at compile time.
+A data race is similar to a race condition and happens when these three behaviors occur:
+
+1) Two or more pointers access the same data at the same time.
+2) At least one of the pointers is being used to write to the data.
+3) There’s no mechanism being used to synchronize access to the data.
+Data races cause undefined behavior and can be difficult to diagnose and fix when you’re trying to track them down at runtime;
+Rust prevents this problem by refusing to compile code with data races!
+(data_race.rs meets all three with threads, and shows the two ways Rust lets threads share a counter)
+
+
+Users of an immutable reference don’t expect the value to suddenly change out from under them! However, multiple immutable references are allowed 
+because no one who is just reading the data has the ability to affect anyone else’s reading of the data.
+
+
+
+**Dangling References**
+In languages with pointers, it’s easy to erroneously create a dangling pointer;
+a pointer that references a location in memory that may have been given to someone else—by freeing some memory while preserving a pointer to that memory.
+In Rust, by contrast, the compiler guarantees that references will never be dangling references:
+if you have a reference to some data, **the compiler will ensure that the data will not go out of scope before the reference to the data does.**
+
+
+Let’s recap what we’ve discussed about references:
+
+At any given time, you can have either one mutable reference or any number of immutable references.
+References must always be valid.
+
+*/
+
+pub mod borrow_cell;
+pub mod checker;
+pub mod data_race;
+pub mod edit;
+pub mod gap_buffer;
+pub mod text_metrics;
+
+use borrow_cell::{BorrowCell, BorrowError};
+use common::{Chapter, Demo, Reporter, say};
+use data_race::Workload;
+use text_metrics::TextMetrics;
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch04/borrowing",
+    package: "borrowing",
+    description: "References and borrowing",
+    banner: Some("Reference and borrowing demo!"),
+    demos: &[
+        Demo {
+            name: "greet_length_demo",
+            description: "Borrow a `String` to read its length",
+            run: |out| {
+                greet_length_demo(out);
+            },
+        },
+        Demo {
+            name: "greet_metrics_demo",
+            description: "Bytes, `char`s, graphemes and columns of names `len()` miscounts",
+            run: |out| {
+                greet_metrics_demo(out);
+            },
+        },
+        Demo {
+            name: "mutable_reference_demo",
+            description: "Modify a `String` through a `&mut` reference",
+            run: |out| {
+                mutable_reference_demo(out);
+            },
+        },
+        Demo {
+            name: "edit_in_place_demo",
+            description: "The in-place edits of `edit` on one `&mut String`, and when it reallocates",
+            run: |out| {
+                edit_in_place_demo(out);
+            },
+        },
+        Demo {
+            name: "greet_length_at_runtime_demo",
+            description: "`greet_length_demo` with its borrows checked and logged at runtime",
+            run: |out| {
+                greet_length_at_runtime_demo(out);
+            },
+        },
+        Demo {
+            name: "mutable_reference_at_runtime_demo",
+            description: "The `r1`/`r2` conflict the compiler refuses, refused at runtime instead",
+            run: |out| {
+                mutable_reference_at_runtime_demo(out);
+            },
+        },
+        Demo {
+            name: "data_race_demo",
+            description: "Threads sharing a counter through a `Mutex` and an atomic, none lost",
+            run: |out| {
+                data_race_demo(out);
+            },
+        },
+    ],
+};
+
+/// Borrows a greeting to measure it, returns the length.
+pub fn greet_length_demo(out: &mut dyn Reporter) -> usize {
+    let greet = String::from("Hello from Rust Programming Language!");
+    let length = greet_length(&greet);
+    say!(out, "{} with length, `#{}`", greet, length);
+    length
+}
+
+/// [`greet_length_demo`] with names whose length in bytes isn't their length: the bytes,
+/// `char`s, graphemes and columns of each. Returns those of `"Ιωάννης"`.
+pub fn greet_metrics_demo(out: &mut dyn Reporter) -> TextMetrics {
+    let names = ["Ioannis", "Ιωάννης", "Ιωα\u{301}ννης", "山田太郎", "👩‍💻", "🇬🇷"];
+    // `{:<10}` pads with `char`s, which doesn't line up `山田太郎` nor `👩‍💻`, columns do
+    let column = names.iter().map(|name| text_metrics::width(name)).max().unwrap_or(0);
+    say!(out, "{}  bytes  chars  graphemes  columns", pad("name", column));
+    for name in names {
+        let metrics = TextMetrics::of(name);
+        say!(out, "{}  {:>5}  {:>5}  {:>9}  {:>7}", pad(name, column), metrics.bytes, metrics.chars, metrics.graphemes, metrics.width);
+    }
+    say!(out, "(graphemes as Unicode {} splits them)", text_metrics::UNICODE_VERSION);
+    TextMetrics::of(names[1])
+}
+
+// `text` and the spaces that make it `columns` wide in a terminal.
+fn pad(text: &str, columns: usize) -> String {
+    format!("{text}{}", " ".repeat(columns.saturating_sub(text_metrics::width(text))))
+}
+
+/// Borrows `x` mutably twice, one after the other, and returns it.
+pub fn mutable_reference_demo(out: &mut dyn Reporter) -> String {
+    let mut x = String::from("foooo");
+    let r1  = &mut x ;
+    mutable_reference(out, r1); // r1 is no longer used after this
+    // this is ok because r1 is not valid anymore
+    let r2 = &mut x ;
+    r2.push('!');
+    say!(out, "x after both mutable borrows ended is: `{}`", x);
+    // but this doesn't compile, two mutable borrows alive at the same time
+    // (tests/compile_fail/two_mutable_borrows.rs checks the error is still E0499,
+    // mutable_reference_at_runtime_demo gets the same error at runtime)
+    // let r1 = &mut x;
+    // let r2 = &mut x;
+    // println!("{}, {}", r1, r2);
+    x
+}
+
+/// [`mutable_reference_demo`] going on: the edits of [`edit`] on the same `&mut String`, with
+/// its capacity after each. Only the one that grows it past its capacity reallocates. Returns
+/// the string.
+pub fn edit_in_place_demo(out: &mut dyn Reporter) -> String {
+    let mut s = String::with_capacity(32);
+    s.push_str("  foooo \t");
+    mutable_reference(out, &mut s);
+    type Edit = fn(&mut String);
+    let edits: [(&str, Edit); 6] = [
+        ("trim", edit::trim),
+        ("collapse_whitespace", edit::collapse_whitespace),
+        ("replace_all bar Ιωάννης", |s| edit::replace_all(s, "bar", "Ιωάννης")),
+        ("make_uppercase", edit::make_uppercase),
+        ("insert_at 5 ','", |s| edit::insert_at(s, 5, ",")),
+        ("wrap 12", |s| edit::wrap(s, 12)),
+    ];
+    for (name, edit) in edits {
+        let buffer = s.as_ptr();
+        edit(&mut s);
+        let moved = if s.as_ptr() == buffer { "" } else { ", reallocated" };
+        say!(out, "{:<23} {:<28} capacity {}{}", name, format!("{s:?}"), s.capacity(), moved);
+    }
+    s
+}
+
+/// [`greet_length_demo`] with a [`BorrowCell`]: two shared borrows alive together are fine, a
+/// mutable one next to them is refused. Returns the length.
+pub fn greet_length_at_runtime_demo(out: &mut dyn Reporter) -> usize {
+    let greet = BorrowCell::new("greet", String::from("Hello from Rust Programming Language!"));
+    let length = match (greet.borrow("r1"), greet.borrow("r2")) {
+        (Ok(r1), Ok(r2)) => {
+            let length = greet_length(&r1);
+            say!(out, "{} with length, `#{}`", r2, length);
+            // r1 and r2 are still alive, so this is refused
+            granted(out, greet.borrow_mut("w"));
+            length
+        }
+        (r1, r2) => {
+            granted(out, r1);
+            granted(out, r2);
+            0
+        }
+    }; // r2, then r1, are given back here
+    greet.report(out);
+    length
+}
+
+/// [`mutable_reference_demo`] with a [`BorrowCell`]: `r1` and `r2` one after the other are
+/// fine, alive at the same time the second is refused, with both lines in the error. Returns
+/// `x`.
+pub fn mutable_reference_at_runtime_demo(out: &mut dyn Reporter) -> String {
+    let x = BorrowCell::new("x", String::from("foooo"));
+    if let Some(mut r1) = granted(out, x.borrow_mut("r1")) {
+        mutable_reference(out, &mut r1);
+    } // r1 is given back here
+    if let Some(mut r2) = granted(out, x.borrow_mut("r2")) {
+        r2.push('!');
+    }
+
+    // what `let r1 = &mut x; let r2 = &mut x;` would do, if it compiled
+    let r1 = granted(out, x.borrow_mut("r1"));
+    let r2 = granted(out, x.borrow_mut("r2"));
+    let r3 = granted(out, x.borrow("r3"));
+    drop((r3, r2, r1));
+
+    x.report(out);
+    x.into_inner()
+}
+
+/// Four threads add one to a shared counter, once through a `Mutex` and once through an
+/// atomic, and neither loses an increment. Returns the count of the `Mutex` one.
+pub fn data_race_demo(out: &mut dyn Reporter) -> usize {
+    let workload = Workload { threads: 4, increments: 10_000 };
+    say!(out, "{} threads adding 1 to a shared counter {} times each", workload.threads, workload.increments);
+    let runs = [data_race::with_mutex(workload), data_race::with_atomic(workload)];
+    data_race::report(out, &runs);
+    // the timings change on every run, the snapshot can't have them
+    say!(out, "Unsynchronized, it loses some: cargo run --release -p borrowing --features racy --bin data_race");
+    runs[0].count
+}
+
+// The borrow, or `None` once it was reported why it was refused.
+fn granted<B>(out: &mut dyn Reporter, borrow: Result<B, BorrowError>) -> Option<B> {
+    borrow.map_err(|err| say!(out, "{}", err)).ok()
+}
+
+// the book takes a `&String` on purpose here, a `&str` would be the idiomatic choice
+#[allow(clippy::ptr_arg)]
+/// Length of the string in bytes, `s` is only borrowed. [`TextMetrics`] counts the rest.
+pub fn greet_length(s : &String ) -> usize {
+    s.len()
+    } // Here, s goes out of scope. But because s does not have ownership of what
+      // it refers to, the value is not dropped.
+
+
+ // we can have only one mutable reference.     
+/// Appends `" bar bar"` to the borrowed string.
+pub fn mutable_reference(out: &mut dyn Reporter, s : &mut String) {
+    say!(out, "If we use a mutable reference we can modify the underling string");
+    say!(out, "s entering the function is: `{}`", s);
+    s.push_str(" bar bar");
+    say!(out, "ante now s is: `{}`", s);
+}
+
+// doesn't compile, see tests/compile_fail/dangle.rs
+// fn dangle() -> &String { // dangle returns a reference to a String
+//
+//     let s = String::from("hello"); // s is a new String
+//
+//     &s // we return a reference to the String, s
+// } // Here, s goes out of scope, and is dropped, so its memory goes away.
+//   // Danger!
@@ -0,0 +1,199 @@
+//! How long a string is, four ways.
+//!
+//! `len()` counts the bytes of the UTF-8, which is what `greet_length` returns, and only the
+//! same as what a reader counts for ASCII:
+//!
+//! - `"Ιωάννης"` is 7 letters, 7 `char`s, and 14 bytes: every Greek letter takes two.
+//! - `"e\u{301}"` is one `é`, made of two `char`s: an `e` and the accent that goes on it.
+//! - `"👩‍💻"` is one emoji, made of three `char`s: a woman, a zero width joiner and a laptop.
+//! - `"日本"` is 2 `char`s that take 4 columns of a terminal.
+//!
+//! [`TextMetrics`] has the four: bytes, `char`s, grapheme clusters (the characters a reader
+//! sees, as [UAX #29] splits them) and terminal columns. The Unicode tables it needs are in
+//! `text_metrics/tables.rs`, generated by `src/bin/unicode_tables.rs`.
+//!
+//! [UAX #29]: https://www.unicode.org/reports/tr29/
+
+mod tables;
+
+use std::fmt;
+
+pub use tables::UNICODE_VERSION;
+use tables::{EXTENDED_PICTOGRAPHIC, GRAPHEME_BREAK, WIDE, ZERO_WIDTH};
+
+/// The length of a string, counted four ways.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextMetrics {
+    /// Bytes of UTF-8, what `len()` returns.
+    pub bytes: usize,
+    /// `char`s, Unicode scalar values.
+    pub chars: usize,
+    /// Extended grapheme clusters, the characters a reader counts.
+    pub graphemes: usize,
+    /// Columns of a terminal, see [`width`].
+    pub width: usize,
+}
+
+/// The grapheme clusters of a string, see [`graphemes`].
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+// The Grapheme_Cluster_Break property of a code point, which decides where clusters end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Gcb {
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    Other,
+}
+
+impl TextMetrics {
+    /// Counts `text`.
+    pub fn of(text: &str) -> Self {
+        let (graphemes, width) = graphemes(text).fold((0, 0), |(count, width), grapheme| {
+            (count + 1, width + grapheme_width(grapheme))
+        });
+        TextMetrics {
+            bytes: text.len(),
+            chars: text.chars().count(),
+            graphemes,
+            width,
+        }
+    }
+}
+
+/// The extended grapheme clusters of `text`, each a `&str` of one or more `char`s.
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { rest: text }
+}
+
+/// The columns `text` takes in a terminal.
+///
+/// A grapheme cluster takes the columns of its first character that takes any: two for East
+/// Asian wide and fullwidth characters, none for marks, format characters and controls, one for
+/// everything else. An emoji asking for emoji presentation (with U+FE0F) and a flag take two.
+/// Ambiguous characters, like Greek letters, count one, as outside of East Asian text.
+pub fn width(text: &str) -> usize {
+    graphemes(text).map(grapheme_width).sum()
+}
+
+/// The columns `c` takes on its own, 0, 1 or 2.
+pub fn char_width(c: char) -> usize {
+    if c.is_control() || contains(ZERO_WIDTH, c) {
+        0
+    } else if contains(WIDE, c) {
+        2
+    } else {
+        1
+    }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut prev = grapheme_break(first);
+        // GB11: after an emoji, its Extends, and a ZWJ, the next emoji joins it
+        let mut emoji = is_pictographic(first);
+        let mut emoji_zwj = false;
+        // GB12, GB13: regional indicators pair up, counting them tells which one starts a pair
+        let mut regional = usize::from(prev == Gcb::RegionalIndicator);
+        let mut end = self.rest.len();
+        for (at, c) in chars {
+            let next = grapheme_break(c);
+            if is_break(prev, next, emoji_zwj && is_pictographic(c), regional) {
+                end = at;
+                break;
+            }
+            emoji_zwj = emoji && next == Gcb::Zwj;
+            emoji = is_pictographic(c) || (emoji && next == Gcb::Extend);
+            regional = if next == Gcb::RegionalIndicator {
+                regional + 1
+            } else {
+                0
+            };
+            prev = next;
+        }
+        let (grapheme, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(grapheme)
+    }
+}
+
+impl fmt::Display for TextMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes, {} chars, {} graphemes, {} columns",
+            self.bytes, self.chars, self.graphemes, self.width
+        )
+    }
+}
+
+// The rules of UAX #29 that apply between `prev` and `next`, in the order it gives them.
+fn is_break(prev: Gcb, next: Gcb, joined_emoji: bool, regional: usize) -> bool {
+    use Gcb::*;
+    match (prev, next) {
+        (Cr, Lf) => false,                                                    // GB3
+        (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => true,              // GB4, GB5
+        (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,      // GB6, GB7, GB8
+        (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,              // GB9, GB9a, GB9b
+        (Zwj, _) if joined_emoji => false,                                    // GB11
+        (RegionalIndicator, RegionalIndicator) => regional.is_multiple_of(2), // GB12, GB13
+        _ => true,                                                            // GB999
+    }
+}
+
+fn grapheme_width(grapheme: &str) -> usize {
+    let mut chars = grapheme.chars();
+    let first = chars.next().unwrap_or(' ');
+    let flag = grapheme_break(first) == Gcb::RegionalIndicator && chars.next().is_some();
+    if flag || (is_pictographic(first) && grapheme.contains('\u{FE0F}')) {
+        return 2;
+    }
+    grapheme
+        .chars()
+        .map(char_width)
+        .find(|&width| width > 0)
+        .unwrap_or(0)
+}
+
+fn grapheme_break(c: char) -> Gcb {
+    let c = u32::from(c);
+    GRAPHEME_BREAK
+        .binary_search_by(|&(start, end, _)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .map_or(Gcb::Other, |at| GRAPHEME_BREAK[at].2)
+}
+
+fn is_pictographic(c: char) -> bool {
+    contains(EXTENDED_PICTOGRAPHIC, c)
+}
+
+fn contains(table: &[(u32, u32)], c: char) -> bool {
+    let c = u32::from(c);
+    // the first range that ends at `c` or after it
+    let at = table.partition_point(|&(_, end)| end < c);
+    table.get(at).is_some_and(|&(start, _)| start <= c)
+}
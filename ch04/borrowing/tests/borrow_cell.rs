@@ -0,0 +1,80 @@
+// `BorrowCell` refuses what the borrow checker refuses, and says where both borrows are.
+
+use borrowing::borrow_cell::{BorrowCell, Kind};
+
+#[test]
+fn shared_borrows_live_together() {
+    let greet = BorrowCell::new("greet", String::from("hello"));
+    let r1 = greet.borrow("r1").unwrap();
+    let r2 = greet.borrow("r2").unwrap();
+    assert_eq!(r1.len() + r2.len(), 10);
+}
+
+#[test]
+fn borrows_one_after_the_other_are_fine() {
+    let x = BorrowCell::new("x", String::from("foo"));
+    x.borrow_mut("r1").unwrap().push('!');
+    x.borrow_mut("r2").unwrap().push('?');
+    let loans = x.loans();
+    assert_eq!(
+        loans
+            .iter()
+            .map(|loan| (loan.by, loan.from, loan.until))
+            .collect::<Vec<_>>(),
+        [("r1", 1, Some(2)), ("r2", 3, Some(4))]
+    );
+    assert_eq!(x.into_inner(), "foo!?");
+}
+
+#[test]
+fn a_second_mutable_borrow_is_e0499_naming_both_sites() {
+    let x = BorrowCell::new("x", 5);
+    let r1 = x.borrow_mut("r1").unwrap();
+    let first_line = line!() - 1;
+    let err = x.borrow_mut("r2").unwrap_err();
+    let second_line = line!() - 1;
+
+    assert_eq!(err.code(), "E0499");
+    assert_eq!((err.by, err.conflict.by), ("r2", "r1"));
+    assert_eq!(
+        (err.location.line(), err.conflict.location.line()),
+        (second_line, first_line)
+    );
+    let message = err.to_string();
+    assert!(
+        message.starts_with("error[E0499]: cannot borrow `x` as mutable more than once at a time")
+    );
+    // both sites, as `file:line:col`, each with its borrower
+    let lines: Vec<&str> = message.lines().collect();
+    let site = |line: u32| format!("  --> {}:{line}:", file!());
+    assert!(lines[1].starts_with(&site(second_line)), "{message}");
+    assert!(lines[1].ends_with(": `r2` borrows `x` as mutable"));
+    assert!(lines[2].starts_with(&site(first_line)), "{message}");
+    assert!(lines[2].ends_with(": `r1` borrowed `x` as mutable at step 1, and is still alive"));
+    drop(r1);
+    assert!(x.borrow_mut("r2").is_ok());
+}
+
+#[test]
+fn mixing_shared_and_mutable_is_e0502() {
+    let x = BorrowCell::new("x", vec![1, 2, 3]);
+    {
+        let _r = x.borrow("r").unwrap();
+        let err = x.borrow_mut("w").unwrap_err();
+        assert_eq!(
+            (err.code(), err.kind, err.conflict.kind),
+            ("E0502", Kind::Mutable, Kind::Shared)
+        );
+        assert!(
+            err.to_string()
+                .contains("cannot borrow `x` as mutable because it is also borrowed as immutable")
+        );
+    }
+    let _w = x.borrow_mut("w").unwrap();
+    let err = x.borrow("r").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cannot borrow `x` as immutable because it is also borrowed as mutable")
+    );
+    assert_eq!(x.loans().last().unwrap().until, None);
+}
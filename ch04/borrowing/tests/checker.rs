@@ -0,0 +1,182 @@
+// The toy borrow checker on the programs of `tests/fixtures/`, which are real Rust: each one
+// says in its header which error it has,
+//
+//     // expect-error: E0382
+//     // expect-message: borrow of moved value: `s1`
+//
+// or `// expect: ok`, and the toy checker must agree with the header, and with rustc.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use borrowing::checker::{check, render};
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    fixtures.sort();
+    assert!(!fixtures.is_empty(), "no fixtures in {}", dir.display());
+    fixtures
+}
+
+fn headers<'a>(source: &'a str, key: &str) -> Vec<&'a str> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix("//"))
+        .filter_map(|line| line.trim().strip_prefix(key)?.strip_prefix(':'))
+        .map(str::trim)
+        .collect()
+}
+
+#[test]
+fn every_fixture_gets_the_errors_of_its_header() {
+    let mut failures = Vec::new();
+    for fixture in fixtures() {
+        let name = fixture.file_name().unwrap().to_string_lossy().to_string();
+        let source = fs::read_to_string(&fixture).unwrap();
+        let diagnostics = check(&source);
+        let codes: Vec<&str> = diagnostics.iter().filter_map(|d| d.code).collect();
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+
+        if headers(&source, "expect") == ["ok"] {
+            if !diagnostics.is_empty() {
+                failures.push(format!(
+                    "{name}: expected no errors, got\n{}",
+                    render(&name, &source, &diagnostics)
+                ));
+            }
+            continue;
+        }
+        let expected = headers(&source, "expect-error");
+        assert!(
+            !expected.is_empty(),
+            "{name}: no `// expect-error:` nor `// expect: ok` header"
+        );
+        if codes != expected {
+            failures.push(format!(
+                "{name}: expected {expected:?}, got\n{}",
+                render(&name, &source, &diagnostics)
+            ));
+        }
+        for message in headers(&source, "expect-message") {
+            if !messages.contains(&message) {
+                failures.push(format!(
+                    "{name}: no error says `{message}`, got {messages:?}"
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+// The fixtures are what the chapters show, so rustc has to agree with the toy checker on them.
+#[test]
+fn rustc_finds_the_same_errors() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("checker");
+    fs::create_dir_all(&out_dir).unwrap();
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let mut failures = Vec::new();
+    for fixture in fixtures() {
+        let name = fixture.file_name().unwrap().to_string_lossy().to_string();
+        let source = fs::read_to_string(&fixture).unwrap();
+        let output = Command::new(&rustc)
+            .args([
+                "--edition",
+                "2024",
+                "--crate-type",
+                "lib",
+                "--emit",
+                "metadata",
+            ])
+            .args(["--error-format", "short", "-A", "warnings", "--out-dir"])
+            .arg(&out_dir)
+            .arg(&fixture)
+            .output()
+            .expect("failed to run rustc");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let rustc_codes: Vec<&str> = stderr
+            .lines()
+            .filter_map(|line| line.split("error[").nth(1)?.split(']').next())
+            .collect();
+        let codes: Vec<&str> = check(&source).iter().filter_map(|d| d.code).collect();
+        if rustc_codes != codes {
+            failures.push(format!(
+                "{name}: rustc says {rustc_codes:?}, the toy checker {codes:?}"
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn errors_are_rendered_like_rustc() {
+    let source = "fn main() {\n    let mut x = String::from(\"foooo\");\n    let r1 = &mut x;\n    let r2 = &mut x;\n    println!(\"{}, {}\", r1, r2);\n}\n";
+    assert_eq!(
+        render("main.rs", source, &check(source)),
+        "\
+error[E0499]: cannot borrow `x` as mutable more than once at a time
+ --> main.rs:4:14
+  |
+3 |     let r1 = &mut x;
+  |              ------ first mutable borrow occurs here
+4 |     let r2 = &mut x;
+  |              ^^^^^^ second mutable borrow occurs here
+5 |     println!(\"{}, {}\", r1, r2);
+  |                        -- first borrow later used here
+
+error: aborting due to 1 previous error
+"
+    );
+}
+
+#[test]
+fn syntax_errors_have_no_code() {
+    let diagnostics = check("fn main() { let s = ; }");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, None);
+    assert_eq!(diagnostics[0].message, "expected an expression, found `;`");
+}
+
+#[test]
+fn rust_outside_the_subset_is_unsupported_not_wrong() {
+    // (source, what isn't supported)
+    let cases = [
+        ("fn main() { let a = 1; let b = 2; let c = a + b; }", "`+`"),
+        ("fn main() { let v = vec![1]; }", "`[`"),
+        ("fn main() { let x = 5; if x { } }", "`if`"),
+        ("fn main() { let same = a == b; }", "`==`"),
+        ("fn main() { let v: Vec<i32> = x; }", "`<`"),
+        ("struct Point;", "`struct`"),
+    ];
+    for (source, what) in cases {
+        let diagnostics = check(source);
+        assert_eq!(diagnostics.len(), 1, "{source}");
+        assert!(diagnostics[0].unsupported, "{source}");
+        assert_eq!(
+            diagnostics[0].message,
+            format!("{what} isn't supported by this checker")
+        );
+    }
+    let source = "fn main() {\n    let c = a + b;\n}\n";
+    assert_eq!(
+        render("main.rs", source, &check(source)),
+        "\
+unsupported: `+` isn't supported by this checker
+ --> main.rs:2:15
+  |
+2 |     let c = a + b;
+  |               ^ this checker only reads the Rust of the ownership chapters
+
+note: the program may well compile, rustc can tell
+"
+    );
+    // a mistake is still a syntax error
+    for source in ["fn main() { let s = ; }", "fn main() { let s = `x`; }"] {
+        assert!(!check(source)[0].unsupported, "{source}");
+    }
+}
@@ -0,0 +1,41 @@
+// The counters of `data_race`: the synchronized ones count every increment, the racy one can
+// only lose some.
+
+use borrowing::data_race::{self, Workload};
+
+const WORKLOAD: Workload = Workload {
+    threads: 4,
+    increments: 5_000,
+};
+
+#[test]
+fn a_mutex_and_an_atomic_lose_nothing() {
+    for run in [
+        data_race::with_mutex(WORKLOAD),
+        data_race::with_atomic(WORKLOAD),
+    ] {
+        assert_eq!((run.count, run.lost()), (20_000, 0), "{}", run.name);
+    }
+}
+
+#[test]
+fn runs_has_the_racy_counter_only_with_the_feature() {
+    let names: Vec<&str> = data_race::runs(WORKLOAD)
+        .iter()
+        .map(|run| run.name)
+        .collect();
+    let mut expected = vec!["Arc<Mutex<usize>>", "Arc<AtomicUsize>"];
+    if cfg!(feature = "racy") {
+        expected.push("racy usize");
+    }
+    assert_eq!(names, expected);
+}
+
+// how many are lost depends on the scheduler, more than it should can't be counted
+#[cfg(feature = "racy")]
+#[test]
+fn the_racy_counter_never_counts_more() {
+    let run = data_race::racy(WORKLOAD);
+    assert!(run.count <= run.expected);
+    assert_eq!(run.count + run.lost(), run.expected);
+}
@@ -0,0 +1,85 @@
+// The `ed` binary on a script of commands, as someone would type them.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn ed(args: &[&str], script: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ed"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(script.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn appends_substitutes_deletes_undoes_and_writes() {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("ed_notes.txt");
+    let _ = std::fs::remove_file(&path);
+    let path = path.to_str().unwrap();
+    let script = "\
+a
+Hello from Rust
+foooo bar bar
+Ιωάννης
+.
+2s/bar/baz/g
+,n
+1d
+u
+0a
+first
+.
+$s/ω/Ω/
+,p
+w
+q
+";
+    assert_eq!(
+        ed(&[path], script),
+        format!(
+            "\
+{path}: no such file, it will be created by `w`
+1\tHello from Rust
+2\tfoooo baz baz
+3\tΙωάννης
+first
+Hello from Rust
+foooo baz baz
+ΙΩάννης
+51
+"
+        )
+    );
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        "first\nHello from Rust\nfoooo baz baz\nΙΩάννης\n"
+    );
+    // opened again, it prints the size and has the lines
+    assert_eq!(ed(&[path], "2p\n$n\n"), "51\nHello from Rust\n4\tΙΩάννης\n");
+}
+
+#[test]
+fn mistakes_print_a_question_mark_and_why() {
+    let script = "p\nu\nx\n1,2d\nw\na\nfoo\n.\ns/a/b\n";
+    assert_eq!(
+        ed(&[], script),
+        "\
+? no lines 0..0, there are 0
+? nothing to undo or redo
+? unknown command `x`
+? no lines 1..2, there are 0
+? no file to write to, `w file` names one
+? expected s/old/new/ or s/old/new/g
+"
+    );
+}
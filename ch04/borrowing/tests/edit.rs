@@ -0,0 +1,153 @@
+// The in-place edits of `edit`: what they make of a string, and that the ones that can't grow it
+// keep its buffer.
+
+use borrowing::edit;
+
+// `s` after `f`, and whether it is still in the same buffer, with the same capacity.
+fn edited(s: &str, capacity: usize, f: impl FnOnce(&mut String)) -> (String, bool) {
+    let mut s = {
+        let mut owned = String::with_capacity(capacity);
+        owned.push_str(s);
+        owned
+    };
+    let (ptr, capacity) = (s.as_ptr(), s.capacity());
+    f(&mut s);
+    let same_buffer = s.as_ptr() == ptr && s.capacity() == capacity;
+    (s, same_buffer)
+}
+
+#[test]
+fn trim_keeps_the_buffer() {
+    assert_eq!(
+        edited(" \t foo bar \n", 16, edit::trim),
+        (String::from("foo bar"), true)
+    );
+    assert_eq!(edited("   ", 16, edit::trim), (String::new(), true));
+}
+
+#[test]
+fn case_mapping_matches_std() {
+    for text in [
+        "Hello from Rust Programming Language!",
+        "Ιωάννης",
+        "ΟΔΥΣΣΕΥΣ ΚΑΙ ΠΗΝΕΛΟΠΗ, Σ",
+        "straße",
+        "İstanbul",
+        "ﬁle ǅ",
+    ] {
+        let (upper, _) = edited(text, 0, edit::make_uppercase);
+        let (lower, _) = edited(text, 0, edit::make_lowercase);
+        assert_eq!((upper, lower), (text.to_uppercase(), text.to_lowercase()));
+    }
+    // every `char` that has a case mapping, on its own and after a letter
+    let mapped = |c: &char| c.to_uppercase().ne([*c]) || c.to_lowercase().ne([*c]);
+    for c in (0..=0x10FFFF).filter_map(char::from_u32).filter(mapped) {
+        for text in [c.to_string(), format!("a{c}")] {
+            let (upper, _) = edited(&text, 0, edit::make_uppercase);
+            let (lower, _) = edited(&text, 0, edit::make_lowercase);
+            assert_eq!(upper, text.to_uppercase(), "{c:?}");
+            assert_eq!(lower, text.to_lowercase(), "{c:?}");
+        }
+    }
+}
+
+#[test]
+fn case_mapping_reallocates_only_past_the_capacity() {
+    assert_eq!(
+        edited("Ιωάννης", 14, edit::make_uppercase),
+        (String::from("ΙΩΆΝΝΗΣ"), true)
+    );
+    // ß is 2 bytes, SS as many
+    assert_eq!(
+        edited("straße", 7, edit::make_uppercase),
+        (String::from("STRASSE"), true)
+    );
+    // İ is 2 bytes, i̇ 3
+    let (lower, same_buffer) = edited("İİİİ", 8, edit::make_lowercase);
+    assert_eq!((lower.as_str(), same_buffer), ("i̇i̇i̇i̇", false));
+}
+
+#[test]
+fn replace_all_shrinking_growing_and_same_length() {
+    let cases = [
+        ("foo bar foo", "foo", "x", "x bar x"),
+        ("foo bar foo", "foo", "", " bar "),
+        ("foo bar foo", "foo", "baz", "baz bar baz"),
+        ("foo bar foo", "foo", "Ιωάννης", "Ιωάννης bar Ιωάννης"),
+        ("aaaa", "aa", "b", "bb"),
+        ("aaa", "a", "aa", "aaaaaa"),
+        ("no match", "foo", "bar", "no match"),
+        ("日本語の日本", "日本", "に", "に語のに"),
+    ];
+    for (text, from, to, expected) in cases {
+        let (replaced, _) = edited(text, 0, |s| edit::replace_all(s, from, to));
+        assert_eq!(replaced, expected, "{from:?} -> {to:?} in {text:?}");
+        assert_eq!(replaced, text.replace(from, to));
+    }
+    // a `from` that overlaps itself matches where `str::replace` does, from the left, whether
+    // `to` is shorter, as long or longer
+    for (text, from) in [
+        ("aaa", "aa"),
+        ("ababa", "aba"),
+        ("aaaaa", "aa"),
+        ("xaaay", "aa"),
+    ] {
+        for to in ["", "z", "zz", "xyz", "XYZW", "日本"] {
+            let (replaced, _) = edited(text, 0, |s| edit::replace_all(s, from, to));
+            assert_eq!(
+                replaced,
+                text.replace(from, to),
+                "{from:?} -> {to:?} in {text:?}"
+            );
+        }
+    }
+    assert_eq!(
+        edited("aaa", 0, |s| edit::replace_all(s, "aa", "xyz")).0,
+        "xyza"
+    );
+    assert_eq!(
+        edited("ababa", 0, |s| edit::replace_all(s, "aba", "XYZW")).0,
+        "XYZWba"
+    );
+    // not longer: never reallocates, longer: only past the capacity
+    assert!(edited("foo bar foo", 11, |s| edit::replace_all(s, "foo", "x")).1);
+    assert!(edited("foo bar foo", 15, |s| edit::replace_all(s, "foo", "fooo")).1);
+    assert!(!edited("foo bar foo", 11, |s| edit::replace_all(s, "foo", "fooo")).1);
+}
+
+#[test]
+fn collapse_whitespace_keeps_the_buffer() {
+    assert_eq!(
+        edited("  foo \t\n bar\u{3000}baz  ", 32, edit::collapse_whitespace),
+        (String::from(" foo bar baz "), true)
+    );
+}
+
+#[test]
+fn insert_and_delete_count_chars() {
+    let (s, same_buffer) = edited("Ιωάννης", 32, |s| {
+        edit::insert_at(s, 0, "« ");
+        edit::insert_at(s, 9, " »");
+        edit::delete(s, 3..7);
+    });
+    assert_eq!((s.as_str(), same_buffer), ("« Ιης »", true));
+}
+
+#[test]
+#[should_panic(expected = "char 8 is out of bounds")]
+fn insert_past_the_end_panics() {
+    edit::insert_at(&mut String::from("Ιωάννης"), 8, "!");
+}
+
+#[test]
+fn wrap_breaks_at_spaces_by_columns() {
+    let text = "Hello from Rust Programming Language! 日本語 日本語 unbreakablelongword end";
+    let (wrapped, same_buffer) = edited(text, 0, |s| edit::wrap(s, 12));
+    assert!(same_buffer);
+    assert_eq!(
+        wrapped,
+        "Hello from\nRust\nProgramming\nLanguage!\n日本語\n日本語\nunbreakablelongword\nend"
+    );
+    let (wrapped, _) = edited("one\ntwo three four", 0, |s| edit::wrap(s, 9));
+    assert_eq!(wrapped, "one\ntwo three\nfour");
+}
@@ -0,0 +1,8 @@
+// ch04/borrowing `dangle`: a returned reference with nothing to borrow from.
+// expect-error: E0106
+// expect-message: missing lifetime specifier
+
+fn dangle() -> &String {
+    let s = String::from("hello");
+    &s
+}
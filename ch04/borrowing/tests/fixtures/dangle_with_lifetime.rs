@@ -0,0 +1,8 @@
+// `dangle` with a lifetime: the signature is fine, but `s` is dropped when the function returns.
+// expect-error: E0515
+// expect-message: cannot return reference to local variable `s`
+
+fn dangle<'a>() -> &'a String {
+    let s = String::from("hello");
+    &s
+}
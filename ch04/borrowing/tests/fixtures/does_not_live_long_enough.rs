@@ -0,0 +1,12 @@
+// `x` is dropped at the end of its block while `r` still points to it.
+// expect-error: E0597
+// expect-message: `x` does not live long enough
+
+fn main() {
+    let r;
+    {
+        let x = 5;
+        r = &x;
+    }
+    println!("r: {r}");
+}
@@ -0,0 +1,10 @@
+// ch04/ownership_intro `move_demo_1`: `s1` is used after it moved to `s2`.
+// expect-error: E0382
+// expect-message: borrow of moved value: `s1`
+
+fn main() {
+    let s1 = String::from("foo");
+    let s2 = s1;
+    println!("{}, world!", s1);
+    println!("{}", s2);
+}
@@ -0,0 +1,10 @@
+// ch04/ownership_intro `move_demo_1` as it is: only `s2` is used after the move.
+// expect: ok
+
+fn main() {
+    let s1 = String::from("foo");
+    let s2 = s1;
+    println!("But we are are able to use s2 normally, s2 is (the value from s1): `{}`", s2);
+    let len = s2.len();
+    println!("{len}");
+}
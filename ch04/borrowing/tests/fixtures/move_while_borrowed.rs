@@ -0,0 +1,14 @@
+// A value can't move while a reference to it is still used.
+// expect-error: E0505
+// expect-message: cannot move out of `s` because it is borrowed
+
+fn take(s: String) {
+    println!("{s}");
+}
+
+fn main() {
+    let s = String::from("hello");
+    let r = &s;
+    take(s);
+    println!("{}", r);
+}
@@ -0,0 +1,16 @@
+// ch04/borrowing `mutable_reference_demo` as it is: `r1` is no longer used when `r2` borrows.
+// expect: ok
+
+fn mutable_reference(s: &mut String) {
+    println!("s entering the function is: `{}`", s);
+    s.push_str(" bar bar");
+}
+
+fn main() {
+    let mut x = String::from("foooo");
+    let r1 = &mut x;
+    mutable_reference(r1);
+    let r2 = &mut x;
+    r2.push('!');
+    println!("x after both mutable borrows ended is: `{}`", x);
+}
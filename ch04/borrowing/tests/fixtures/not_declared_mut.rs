@@ -0,0 +1,12 @@
+// Variables, and so what they own, are immutable unless declared with `mut`.
+// expect-error: E0596
+// expect-message: cannot borrow `s` as mutable, as it is not declared as mutable
+
+fn change(some_string: &mut String) {
+    some_string.push_str(", world");
+}
+
+fn main() {
+    let s = String::from("hello");
+    change(&mut s);
+}
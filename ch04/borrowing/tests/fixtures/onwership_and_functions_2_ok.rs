@@ -0,0 +1,20 @@
+// ch04/ownership_intro `onwership_and_functions_2` as it is: every value is used by its owner.
+// expect: ok
+
+fn gives_ownership() -> String {
+    let some_string = String::from("bar string");
+    some_string
+}
+
+fn take_and_give_back(s: String) -> String {
+    s
+}
+
+fn onwership_and_functions_2() -> usize {
+    let s1 = gives_ownership();
+    println!("s took the onwership and has value: `{}`", s1);
+    let s2 = String::from("hello");
+    let s3 = take_and_give_back(s2);
+    println!("I can use s3 because it's a move from s2: s3 is: `{}`", s3);
+    s3.len()
+}
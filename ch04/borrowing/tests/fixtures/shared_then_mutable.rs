@@ -0,0 +1,16 @@
+// Readers of `&s` don't expect it to change: no `&mut s` while they are used.
+// expect-error: E0502
+// expect-message: cannot borrow `s` as mutable because it is also borrowed as immutable
+
+fn greet_length(s: &String) -> usize {
+    s.len()
+}
+
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &s;
+    let r3 = &mut s;
+    println!("{}, {}, and {}", r1, r2, r3);
+    println!("{}", greet_length(&s));
+}
@@ -0,0 +1,16 @@
+// The readers are done before the writer comes: the scopes of the borrows don't overlap.
+// expect: ok
+
+fn greet_length(s: &String) -> usize {
+    s.len()
+}
+
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &s;
+    println!("{} and {}", r1, r2);
+    let r3 = &mut s;
+    r3.push_str(", world");
+    println!("{}", greet_length(&s));
+}
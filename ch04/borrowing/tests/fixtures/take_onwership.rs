@@ -0,0 +1,20 @@
+// ch04/ownership_intro `onwership_and_functions_1`: `s` moves into the function, `x` is copied.
+// expect-error: E0382
+// expect-message: use of moved value: `s`
+
+fn take_onwership_from_something(s: String) {
+    println!("s has value: `{}`", s);
+}
+
+fn take_copy(s: i32) {
+    println!("s in this case is a new copy in the stack, with value: `{}`", s);
+}
+
+fn onwership_and_functions_1() {
+    let s = String::from("foo string");
+    take_onwership_from_something(s);
+    let x = 10;
+    take_copy(x);
+    take_copy(x);
+    take_onwership_from_something(s);
+}
@@ -0,0 +1,116 @@
+// The gap buffer against a `String` doing the same edits, and its undo, redo and lines.
+
+use borrowing::gap_buffer::GapBuffer;
+
+#[test]
+fn edits_at_the_cursor() {
+    let mut buffer = GapBuffer::from("Hello world!");
+    buffer.set_cursor(5);
+    buffer.insert(",");
+    assert_eq!(buffer.cursor(), 6);
+    buffer.set_cursor(7);
+    assert_eq!(buffer.delete(5), "world");
+    buffer.insert("Ιωάννης");
+    assert_eq!(buffer.to_string(), "Hello, Ιωάννης!");
+    assert_eq!(buffer.len(), "Hello, Ιωάννης!".len());
+    // the gap is between "Ιωάννης" and "!", a view of both moves it away
+    assert_eq!(buffer.slices(7..22), ("Ιωάννης", "!"));
+    assert_eq!(buffer.view(7..22), "Ιωάννης!");
+    assert_eq!(buffer.as_str(), "Hello, Ιωάννης!");
+}
+
+#[test]
+fn undo_and_redo_every_edit() {
+    let mut buffer = GapBuffer::from("foo");
+    buffer.insert(" bar");
+    buffer.replace(0..3, "baz");
+    buffer.set_cursor(3);
+    buffer.delete(4);
+    let versions = ["foo", "foo bar", "baz bar", "baz"];
+    for version in versions.iter().rev().skip(1) {
+        assert!(buffer.undo());
+        assert_eq!(buffer.to_string(), *version);
+    }
+    assert!(!buffer.undo());
+    for version in &versions[1..] {
+        assert!(buffer.redo());
+        assert_eq!(buffer.to_string(), *version);
+    }
+    assert!(!buffer.redo());
+
+    // an edit after an undo forgets what was undone
+    buffer.undo();
+    buffer.insert("!");
+    assert!(!buffer.redo());
+    assert_eq!(buffer.to_string(), "baz bar!");
+}
+
+#[test]
+fn lines_follow_the_edits() {
+    let mut buffer = GapBuffer::from("one\ntwo\nthree");
+    assert_eq!(buffer.line_count(), 3);
+    assert_eq!(buffer.line(2), Some("three"));
+    buffer.set_cursor(4);
+    buffer.insert("one and a half\n");
+    assert_eq!(buffer.line_count(), 4);
+    assert_eq!(buffer.line(1), Some("one and a half\n"));
+    assert_eq!(buffer.line_of(buffer.line_range(3).unwrap().start), 3);
+    // deleting across lines joins them
+    buffer.replace(2..23, "");
+    assert_eq!(buffer.to_string(), "onthree");
+    assert_eq!(buffer.line_count(), 1);
+    buffer.undo();
+    assert_eq!(buffer.line(2), Some("two\n"));
+    assert_eq!(GapBuffer::from("a\n").line_count(), 1);
+    assert_eq!(GapBuffer::new().line_count(), 0);
+}
+
+#[test]
+fn random_edits_match_a_string() {
+    let words = [
+        "",
+        "a",
+        "bc\n",
+        "Ιω",
+        "日本語",
+        "\n\n",
+        "👩‍💻",
+        "long line of text ",
+    ];
+    let mut buffer = GapBuffer::new();
+    let mut text = String::new();
+    let mut seed = 7u64;
+    let mut random = |n: usize| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize % n.max(1)
+    };
+    for _ in 0..2_000 {
+        let boundaries: Vec<usize> = (0..=text.len())
+            .filter(|&at| text.is_char_boundary(at))
+            .collect();
+        let start = boundaries[random(boundaries.len())];
+        let end = *boundaries
+            .iter()
+            .filter(|&&at| at >= start)
+            .nth(random(3))
+            .unwrap_or(&start);
+        let word = words[random(words.len())];
+        buffer.replace(start..end, word);
+        text.replace_range(start..end, word);
+        assert_eq!(buffer.to_string(), text);
+        let lines: Vec<&str> = text.split_inclusive('\n').collect();
+        assert_eq!(buffer.line_count(), lines.len());
+        let n = random(lines.len());
+        assert_eq!(buffer.line(n), lines.get(n).copied());
+    }
+    while buffer.undo() {}
+    assert!(buffer.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "in the middle of a char")]
+fn positions_must_be_on_char_boundaries() {
+    GapBuffer::from("Ιωάννης").set_cursor(1);
+}
@@ -0,0 +1,80 @@
+// `TextMetrics` on the strings that surprise `len()`: one row per string, with what each of the
+// four counts should be.
+
+use borrowing::text_metrics::{self, TextMetrics};
+
+// (what it is, the string, bytes, chars, graphemes, columns)
+const CASES: &[(&str, &str, usize, usize, usize, usize)] = &[
+    ("empty", "", 0, 0, 0, 0),
+    ("ASCII", "Hello", 5, 5, 5, 5),
+    ("Greek", "Ιωάννης", 14, 7, 7, 7),
+    (
+        "Greek, accent as a combining mark",
+        "Ιωα\u{301}ννης",
+        16,
+        8,
+        7,
+        7,
+    ),
+    ("two combining marks", "e\u{301}\u{323}", 5, 3, 1, 1),
+    ("a combining mark on its own", "\u{301}", 2, 1, 1, 0),
+    ("Japanese", "日本語", 9, 3, 3, 6),
+    ("Korean syllables", "한국어", 9, 3, 3, 6),
+    (
+        "Korean syllable from its jamo",
+        "\u{1112}\u{1161}\u{11AB}",
+        9,
+        3,
+        1,
+        2,
+    ),
+    ("fullwidth Latin", "ｈｅｌｌｏ", 15, 5, 5, 10),
+    ("emoji", "🦀", 4, 1, 1, 2),
+    ("emoji with a skin tone", "👍🏽", 8, 2, 1, 2),
+    ("emoji presentation selector", "❤\u{FE0F}", 6, 2, 1, 2),
+    ("ZWJ sequence", "👩‍💻", 11, 3, 1, 2),
+    ("ZWJ family", "👨‍👩‍👧‍👦", 25, 7, 1, 2),
+    ("ZWJ between letters", "a\u{200D}b", 5, 3, 2, 2),
+    ("flags", "🇬🇷🇯🇵", 16, 4, 2, 4),
+    ("three regional indicators", "🇬🇷🇯", 12, 3, 2, 3),
+    ("CRLF", "a\r\nb", 4, 4, 3, 2),
+    ("Devanagari", "नमस्ते", 18, 6, 4, 4),
+    ("mixed", "Γειά σου 世界 👋🏼!", 32, 15, 14, 17),
+];
+
+#[test]
+fn every_case_counts_as_expected() {
+    let mut failures = Vec::new();
+    for &(what, text, bytes, chars, graphemes, width) in CASES {
+        let expected = TextMetrics {
+            bytes,
+            chars,
+            graphemes,
+            width,
+        };
+        let actual = TextMetrics::of(text);
+        if actual != expected {
+            failures.push(format!(
+                "{what} {text:?}: expected {expected}, got {actual}"
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn graphemes_split_where_a_reader_would() {
+    let graphemes: Vec<&str> = text_metrics::graphemes("e\u{301}👩‍💻🇬🇷🇯🇵\r\nя").collect();
+    assert_eq!(graphemes, ["e\u{301}", "👩‍💻", "🇬🇷", "🇯🇵", "\r\n", "я"]);
+    // they are slices of the string, in order, with nothing left out
+    assert_eq!(graphemes.concat(), "e\u{301}👩‍💻🇬🇷🇯🇵\r\nя");
+}
+
+#[test]
+fn char_widths() {
+    let widths: Vec<usize> = ['a', 'ω', '日', 'ｈ', '\u{301}', '\u{200D}', '\t', '\u{AD}']
+        .into_iter()
+        .map(text_metrics::char_width)
+        .collect();
+    assert_eq!(widths, [1, 1, 2, 2, 0, 0, 0, 1]);
+}
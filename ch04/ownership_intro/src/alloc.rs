@@ -0,0 +1,138 @@
+//! A global allocator that counts what goes through it.
+//!
+//! Nothing uses it unless a binary installs it, the way `alloc_table` does:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOC: CountingAlloc = CountingAlloc::new();
+//!
+//! let (_, stats) = ALLOC.measure(|| String::from("foo"));
+//! assert_eq!(stats.allocations, 1);
+//! ```
+//!
+//! The counters are shared by every thread, so measure code while nothing else runs.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ops::Sub;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Wraps an allocator (the system one by default) and counts the calls made to it.
+pub struct CountingAlloc<A = System> {
+    inner: A,
+    allocations: AtomicUsize,
+    deallocations: AtomicUsize,
+    reallocations: AtomicUsize,
+    bytes_allocated: AtomicUsize,
+    bytes_freed: AtomicUsize,
+}
+
+/// The counters of a [`CountingAlloc`] at some point, or the difference between two points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub deallocations: usize,
+    pub reallocations: usize,
+    /// Bytes handed out, a reallocation that grows a block counts the bytes it adds.
+    pub bytes_allocated: usize,
+    /// Bytes given back, a reallocation that shrinks a block counts the bytes it removes.
+    pub bytes_freed: usize,
+}
+
+impl CountingAlloc<System> {
+    pub const fn new() -> Self {
+        Self::with(System)
+    }
+}
+
+impl Default for CountingAlloc<System> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> CountingAlloc<A> {
+    pub const fn with(inner: A) -> Self {
+        Self {
+            inner,
+            allocations: AtomicUsize::new(0),
+            deallocations: AtomicUsize::new(0),
+            reallocations: AtomicUsize::new(0),
+            bytes_allocated: AtomicUsize::new(0),
+            bytes_freed: AtomicUsize::new(0),
+        }
+    }
+
+    /// The counters since the program started.
+    pub fn stats(&self) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            deallocations: self.deallocations.load(Ordering::Relaxed),
+            reallocations: self.reallocations.load(Ordering::Relaxed),
+            bytes_allocated: self.bytes_allocated.load(Ordering::Relaxed),
+            bytes_freed: self.bytes_freed.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Runs `f` and returns what it gave back with the allocator calls it made. Whatever the
+    /// result owns is dropped after the measure.
+    pub fn measure<R>(&self, f: impl FnOnce() -> R) -> (R, AllocStats) {
+        let before = self.stats();
+        let result = f();
+        (result, self.stats() - before)
+    }
+}
+
+// SAFETY: every call is forwarded to `inner` with the same arguments, the counters are only
+// updated next to it.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAlloc<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes_allocated
+            .fetch_add(layout.size(), Ordering::Relaxed);
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { self.inner.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes_allocated
+            .fetch_add(layout.size(), Ordering::Relaxed);
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+        unsafe { self.inner.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.deallocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes_freed.fetch_add(layout.size(), Ordering::Relaxed);
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { self.inner.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.reallocations.fetch_add(1, Ordering::Relaxed);
+        let old_size = layout.size();
+        if new_size > old_size {
+            self.bytes_allocated
+                .fetch_add(new_size - old_size, Ordering::Relaxed);
+        } else {
+            self.bytes_freed
+                .fetch_add(old_size - new_size, Ordering::Relaxed);
+        }
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { self.inner.realloc(ptr, layout, new_size) }
+    }
+}
+
+impl Sub for AllocStats {
+    type Output = AllocStats;
+
+    fn sub(self, before: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations - before.allocations,
+            deallocations: self.deallocations - before.deallocations,
+            reallocations: self.reallocations - before.reallocations,
+            bytes_allocated: self.bytes_allocated - before.bytes_allocated,
+            bytes_freed: self.bytes_freed - before.bytes_freed,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! A bump arena for strings: it owns every string put in it, and hands out `&str`s that borrow
+//! the arena. Nothing is freed one by one, everything goes at once when the arena is dropped
+//! or [`reset`](Arena::reset).
+//!
+//! The borrow checker is what makes this safe: a `&str` from [`Arena::alloc_str`] can't
+//! outlive the arena, and `reset` takes `&mut self`, so it can't run while one is still around.
+//!
+//! ```compile_fail
+//! use ownership_intro::arena::Arena;
+//!
+//! let mut arena = Arena::new();
+//! let foo = arena.alloc_str("foo");
+//! arena.reset(); // cannot borrow `arena` as mutable because it is also borrowed as immutable
+//! println!("{foo}");
+//! ```
+
+use std::cell::RefCell;
+
+/// The size of the first chunk, the next ones double.
+pub const CHUNK_SIZE: usize = 256;
+
+/// Owns strings in a few big chunks of bytes instead of one heap buffer each.
+pub struct Arena {
+    // Each chunk is filled up to its capacity but never grows past it, so its buffer never
+    // moves and the `&str`s into it stay valid. The last one is the one being filled.
+    chunks: RefCell<Vec<Vec<u8>>>,
+}
+
+impl Arena {
+    /// An empty arena, which allocates its first chunk on the first string.
+    pub const fn new() -> Self {
+        Arena {
+            chunks: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Copies `s` into the arena and returns the copy, which lives as long as the arena.
+    pub fn alloc_str(&self, s: &str) -> &str {
+        let mut chunks = self.chunks.borrow_mut();
+        let fits = chunks
+            .last()
+            .is_some_and(|chunk| chunk.capacity() - chunk.len() >= s.len());
+        if !fits {
+            let last = chunks.last().map_or(CHUNK_SIZE / 2, Vec::capacity);
+            chunks.push(Vec::with_capacity((last * 2).max(s.len())));
+        }
+        let chunk = chunks.last_mut().unwrap();
+        let start = chunk.len();
+        chunk.extend_from_slice(s.as_bytes());
+        // SAFETY: the bytes were just copied from `s`, so they are UTF-8. They sit in a buffer
+        // that is never reallocated (a chunk doesn't grow past its capacity) nor freed before
+        // the arena is dropped or reset, and both need the `&self` this borrows to be gone.
+        unsafe {
+            let bytes = std::slice::from_raw_parts(chunk.as_ptr().add(start), s.len());
+            std::str::from_utf8_unchecked(bytes)
+        }
+    }
+
+    /// Bytes used by the strings in the arena.
+    pub fn used(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::len).sum()
+    }
+
+    /// Bytes the arena holds on the heap, used or not.
+    pub fn capacity(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::capacity).sum()
+    }
+
+    pub fn chunks(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+
+    /// Forgets every string but keeps the biggest chunk, so filling the arena again doesn't
+    /// allocate until that chunk is full.
+    pub fn reset(&mut self) {
+        let chunks = self.chunks.get_mut();
+        if let Some(mut biggest) = chunks.pop() {
+            biggest.clear();
+            chunks.clear();
+            chunks.push(biggest);
+        }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena::new()
+    }
+}
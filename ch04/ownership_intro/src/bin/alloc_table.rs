@@ -0,0 +1,74 @@
+// What the ownership demos do to the heap, counted by `CountingAlloc`:
+//
+//     cargo run -p ownership_intro --bin alloc_table
+//
+// The demos run with a `NullReporter`, so the counts are the demos' own and not those of
+// printing their output.
+
+use common::NullReporter;
+use ownership_intro::alloc::{AllocStats, CountingAlloc};
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc::new();
+
+fn main() {
+    let mut rows = Vec::new();
+    for demo in ownership_intro::CHAPTER.demos {
+        let ((), stats) = ALLOC.measure(|| (demo.run)(&mut NullReporter));
+        rows.push((demo.name.to_string(), stats));
+    }
+    print_table("demo", &rows);
+
+    // The single steps the notes talk about. Each measure keeps what the step needs outside of
+    // it, so only the step itself is counted.
+    let mut rows = Vec::new();
+    let (s1, stats) = ALLOC.measure(|| String::from("foo"));
+    rows.push((String::from("let s1 = String::from(\"foo\");"), stats));
+
+    let (s2, stats) = ALLOC.measure(|| s1);
+    rows.push((String::from("let s2 = s1;"), stats));
+
+    let (mut s3, stats) = ALLOC.measure(|| s2.clone());
+    rows.push((String::from("let s3 = s2.clone();"), stats));
+
+    let ((), stats) = ALLOC.measure(|| s3.push_str(" bar"));
+    rows.push((String::from("s3.push_str(\" bar\");"), stats));
+
+    let ((), stats) = ALLOC.measure(|| ownership_intro::take_copy(&mut NullReporter, 10));
+    rows.push((String::from("take_copy(out, 10);"), stats));
+
+    let (s4, stats) = ALLOC.measure(|| ownership_intro::take_and_give_back(s3));
+    rows.push((String::from("let s4 = take_and_give_back(s3);"), stats));
+
+    let ((), stats) = ALLOC.measure(|| drop(s2));
+    rows.push((String::from("drop(s2);"), stats));
+
+    let ((), stats) = ALLOC.measure(|| drop(s4));
+    rows.push((String::from("drop(s4);"), stats));
+
+    println!();
+    print_table("step", &rows);
+}
+
+fn print_table(title: &str, rows: &[(String, AllocStats)]) {
+    let width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain([title.len()])
+        .max()
+        .unwrap_or(0);
+    println!(
+        "{title:<width$}  {:>6}  {:>8}  {:>8}  {:>15}  {:>11}",
+        "allocs", "deallocs", "reallocs", "bytes allocated", "bytes freed"
+    );
+    for (name, stats) in rows {
+        println!(
+            "{name:<width$}  {:>6}  {:>8}  {:>8}  {:>15}  {:>11}",
+            stats.allocations,
+            stats.deallocations,
+            stats.reallocations,
+            stats.bytes_allocated,
+            stats.bytes_freed
+        );
+    }
+}
@@ -0,0 +1,97 @@
+// `MyString` and `SmallString` against `String`: the same pushes, timed, with the allocator
+// calls they make.
+//
+//     cargo run --release -p ownership_intro --bin string_bench
+//
+// `String` and `MyString` double their buffer, so the reallocations should match and the times
+// be close. `SmallString` only allocates once the text is past 23 bytes, which the short names
+// of the demos never are: the second table builds them.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ownership_intro::alloc::{AllocStats, CountingAlloc};
+use ownership_intro::my_string::MyString;
+use ownership_intro::small_string::SmallString;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc::new();
+
+const PUSHES: &[usize] = &[16, 1_000, 100_000];
+const RUNS: u32 = 5;
+const PIECE: &str = "ab";
+// the strings the demos create
+const NAMES: &[&str] = &["foo", "Foo", "hello", "foo string", "bar string"];
+const BUILDS: usize = 100_000;
+
+fn main() {
+    println!(
+        "{:<11} {:>8}  {:>8} {:>8} {:>10}  {:>12}",
+        "type", "pushes", "allocs", "reallocs", "capacity", "best time"
+    );
+    for &pushes in PUSHES {
+        row("String", pushes, |n| {
+            let mut s = String::new();
+            for _ in 0..n {
+                s.push_str(black_box(PIECE));
+            }
+            black_box(&s);
+            s.capacity()
+        });
+        row("MyString", pushes, |n| {
+            let mut s = MyString::new();
+            for _ in 0..n {
+                s.push_str(black_box(PIECE));
+            }
+            black_box(&s);
+            s.capacity()
+        });
+        row("SmallString", pushes, |n| {
+            let mut s = SmallString::new();
+            for _ in 0..n {
+                s.push_str(black_box(PIECE));
+            }
+            black_box(&s);
+            s.capacity()
+        });
+    }
+
+    println!();
+    println!(
+        "{:<11} {:>8}  {:>8} {:>8} {:>10}  {:>12}",
+        "type", "names", "allocs", "reallocs", "capacity", "best time"
+    );
+    row("String", BUILDS, |n| {
+        let mut capacity = 0;
+        for i in 0..n {
+            let s = String::from(black_box(NAMES[i % NAMES.len()]));
+            capacity = black_box(&s).capacity();
+        }
+        capacity
+    });
+    row("SmallString", BUILDS, |n| {
+        let mut capacity = 0;
+        for i in 0..n {
+            let s = SmallString::from(black_box(NAMES[i % NAMES.len()]));
+            capacity = black_box(&s).capacity();
+        }
+        capacity
+    });
+}
+
+// Runs `push` (which pushes, or builds, `pushes` times) a few times and keeps the fastest,
+// the counts are the same on every run.
+fn row(name: &str, pushes: usize, push: impl Fn(usize) -> usize) {
+    let mut best = Duration::MAX;
+    let mut stats = AllocStats::default();
+    let mut capacity = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        (capacity, stats) = ALLOC.measure(|| push(pushes));
+        best = best.min(start.elapsed());
+    }
+    println!(
+        "{name:<11} {pushes:>8}  {:>8} {:>8} {capacity:>10}  {:>12?}",
+        stats.allocations, stats.reallocations, best
+    );
+}
@@ -0,0 +1,77 @@
+//! A string interner: every distinct string is stored once, in an [`Arena`], and known by a
+//! small [`Symbol`] that is `Copy`, so it can be passed around without moving or cloning a
+//! `String`.
+//!
+//! ```ignore
+//! let arena = Arena::new();
+//! let mut names = Interner::new(&arena);
+//! let foo = names.intern("foo");
+//! assert_eq!(names.intern("foo"), foo);   // stored once
+//! let s: &str = names.resolve(foo);       // borrows the arena, not the interner
+//! ```
+
+use std::collections::HashMap;
+
+use crate::arena::Arena;
+
+/// A handle to an interned string, only meaningful to the [`Interner`] that made it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+/// Maps strings to [`Symbol`]s and back. The strings are owned by the arena, the interner
+/// only borrows them.
+pub struct Interner<'a> {
+    arena: &'a Arena,
+    symbols: HashMap<&'a str, Symbol>,
+    strings: Vec<&'a str>,
+}
+
+impl Symbol {
+    /// The position of the string in the order it was first interned.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl<'a> Interner<'a> {
+    pub fn new(arena: &'a Arena) -> Self {
+        Interner {
+            arena,
+            symbols: HashMap::new(),
+            strings: Vec::new(),
+        }
+    }
+
+    /// The symbol of `s`, copying `s` into the arena the first time it is seen.
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(s) {
+            return symbol;
+        }
+        let symbol = Symbol(u32::try_from(self.strings.len()).expect("too many symbols"));
+        let s = self.arena.alloc_str(s);
+        self.symbols.insert(s, symbol);
+        self.strings.push(s);
+        symbol
+    }
+
+    /// The symbol of `s`, if it was interned.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.symbols.get(s).copied()
+    }
+
+    /// The string of `symbol`. It borrows the arena, so it can outlive the interner.
+    ///
+    /// Panics if `symbol` comes from another interner with more strings.
+    pub fn resolve(&self, symbol: Symbol) -> &'a str {
+        self.strings[symbol.index()]
+    }
+
+    /// How many distinct strings were interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
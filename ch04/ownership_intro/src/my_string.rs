@@ -0,0 +1,247 @@
+//! A `String` written by hand on top of `std::alloc`, to see what the notes of
+//! `the_string_type_demo` describe: three words on the stack (pointer, length, capacity) and
+//! the bytes in a buffer on the heap.
+//!
+//! [`MyString::diagram`] draws both:
+//!
+//! ```text
+//! stack               heap
+//! +-----+-----+       +---+---+---+---+---+---+---+---+
+//! | ptr |  *--+-----> | F | o | o |   | B | a | r | . |
+//! | len |   7 |       +---+---+---+---+---+---+---+---+
+//! | cap |   8 |         0   1   2   3   4   5   6   7
+//! +-----+-----+
+//! ```
+//!
+//! Growth doubles the capacity, with at least 8 bytes, like `Vec<u8>` (and so `String`) does.
+
+use std::alloc::{self, Layout};
+use std::fmt::{self, Write as _};
+use std::ops::Deref;
+use std::ptr::{self, NonNull};
+
+/// The smallest buffer a `MyString` allocates.
+pub const MIN_CAPACITY: usize = 8;
+// cells drawn in a diagram, the rest of a bigger buffer is left out
+const DIAGRAM_CELLS: usize = 16;
+
+/// A growable UTF-8 string that owns its heap buffer.
+pub struct MyString {
+    // dangling (never read) while `cap` is 0
+    ptr: NonNull<u8>,
+    len: usize,
+    cap: usize,
+}
+
+// SAFETY: `MyString` owns its buffer like a `String` does, nothing else points into it.
+unsafe impl Send for MyString {}
+// SAFETY: `&MyString` only gives shared access to the bytes.
+unsafe impl Sync for MyString {}
+
+impl MyString {
+    /// An empty string, which doesn't allocate.
+    pub const fn new() -> Self {
+        MyString {
+            ptr: NonNull::dangling(),
+            len: 0,
+            cap: 0,
+        }
+    }
+
+    /// An empty string with room for `cap` bytes.
+    pub fn with_capacity(cap: usize) -> Self {
+        let mut s = MyString::new();
+        if cap > 0 {
+            s.realloc(cap);
+        }
+        s
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the first `len` bytes of the buffer are initialized, and only ever written
+        // from a `&str`, so they are valid UTF-8. With `cap == 0` the pointer is dangling but
+        // well aligned, which is fine for an empty slice.
+        unsafe {
+            let bytes = std::slice::from_raw_parts(self.ptr.as_ptr(), self.len);
+            std::str::from_utf8_unchecked(bytes)
+        }
+    }
+
+    /// Appends `s`, growing the buffer when it is too small.
+    pub fn push_str(&mut self, s: &str) {
+        let needed = self.len.checked_add(s.len()).expect("capacity overflow");
+        if needed > self.cap {
+            self.grow(needed);
+        }
+        // SAFETY: the buffer has room for `needed` bytes, and `s` can't point into it since we
+        // hold `&mut self`.
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), self.ptr.as_ptr().add(self.len), s.len());
+        }
+        self.len = needed;
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Room for at least `needed` bytes: twice the capacity, or `needed` if that is more, and
+    /// never less than [`MIN_CAPACITY`]. Doubling keeps `push_str` amortized O(1).
+    fn grow(&mut self, needed: usize) {
+        let doubled = self.cap.saturating_mul(2);
+        self.realloc(doubled.max(needed).max(MIN_CAPACITY));
+    }
+
+    // Moves the bytes to a buffer of exactly `cap` bytes, `cap >= len`.
+    fn realloc(&mut self, cap: usize) {
+        let new_layout = Layout::array::<u8>(cap).expect("capacity overflow");
+        let new_ptr = if self.cap == 0 {
+            // SAFETY: `cap > 0`, so the layout has a non-zero size.
+            unsafe { alloc::alloc(new_layout) }
+        } else {
+            // SAFETY: `ptr` was allocated with the layout of `self.cap` bytes, and the new
+            // size is non-zero and fits in an `isize` (checked by `Layout::array`).
+            unsafe { alloc::realloc(self.ptr.as_ptr(), Self::layout(self.cap), cap) }
+        };
+        self.ptr = NonNull::new(new_ptr).unwrap_or_else(|| alloc::handle_alloc_error(new_layout));
+        self.cap = cap;
+    }
+
+    fn layout(cap: usize) -> Layout {
+        Layout::array::<u8>(cap).expect("capacity overflow")
+    }
+
+    /// The stack triple and the heap buffer as ASCII art. Bytes past `len` are drawn as `.`,
+    /// bytes that are not printable ASCII in hex. The address is left out, it changes on every
+    /// run.
+    pub fn diagram(&self) -> String {
+        let mut out = String::new();
+        let cells = self.cap.min(DIAGRAM_CELLS);
+        let more = if self.cap > cells { " ..." } else { "" };
+        let border = format!("+{}", "---+".repeat(cells));
+        let ptr = if self.cap == 0 {
+            "dangling"
+        } else {
+            "*--+----->"
+        };
+
+        let _ = writeln!(out, "stack               heap");
+        let _ = writeln!(
+            out,
+            "+-----+-----+       {}",
+            if cells > 0 { &border } else { "" }
+        );
+        if self.cap == 0 {
+            let _ = writeln!(out, "| ptr |  {ptr}   (no heap buffer)");
+        } else {
+            let mut bytes = String::from("|");
+            for i in 0..cells {
+                let _ = write!(bytes, "{}|", self.cell(i));
+            }
+            let _ = writeln!(out, "| ptr |  {ptr} {bytes}{more}");
+        }
+        let second = if cells > 0 { &border } else { "" };
+        let _ = writeln!(out, "| len | {:>3} |       {second}", self.len);
+        let indexes: String = (0..cells).map(|i| format!("{i:>3} ")).collect();
+        let _ = writeln!(
+            out,
+            "| cap | {:>3} |       {}",
+            self.cap,
+            indexes.trim_end()
+        );
+        out.push_str("+-----+-----+");
+        out.lines().map(str::trim_end).collect::<Vec<_>>().join("\n")
+    }
+
+    fn cell(&self, i: usize) -> String {
+        if i >= self.len {
+            return String::from(" . ");
+        }
+        let byte = self.as_str().as_bytes()[i];
+        if byte.is_ascii_graphic() || byte == b' ' {
+            format!(" {} ", byte as char)
+        } else {
+            format!("{byte:02x} ")
+        }
+    }
+}
+
+impl Default for MyString {
+    fn default() -> Self {
+        MyString::new()
+    }
+}
+
+impl From<&str> for MyString {
+    /// Allocates exactly `s.len()` bytes, like `String::from`.
+    fn from(s: &str) -> Self {
+        let mut my = MyString::with_capacity(s.len());
+        my.push_str(s);
+        my
+    }
+}
+
+impl Clone for MyString {
+    /// A deep copy: a new heap buffer, as big as the text (like `String::clone`).
+    fn clone(&self) -> Self {
+        MyString::from(self.as_str())
+    }
+}
+
+impl Drop for MyString {
+    fn drop(&mut self) {
+        if self.cap > 0 {
+            // SAFETY: `ptr` was allocated with the layout of `cap` bytes and is freed once,
+            // here.
+            unsafe { alloc::dealloc(self.ptr.as_ptr(), Self::layout(self.cap)) }
+        }
+    }
+}
+
+impl Deref for MyString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for MyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq<str> for MyString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for MyString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
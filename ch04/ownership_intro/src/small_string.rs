@@ -0,0 +1,233 @@
+//! A string that keeps short text inline, in the three words a `String` uses for its pointer,
+//! length and capacity, and only goes to the heap when the text doesn't fit.
+//!
+//! On a 64-bit target that is 24 bytes: up to 23 bytes of text, and a last byte that tells the
+//! two cases apart.
+//!
+//! ```text
+//! inline:  | t e x t . . . (23 bytes)                       | 0x80 + len |
+//! heap:    | ptr (8 bytes)     | len (8 bytes)     | cap (8 bytes, little endian) |
+//! ```
+//!
+//! The capacity of a heap buffer is never above `isize::MAX`, so the top bit of its most
+//! significant byte is always 0. Storing it little endian puts that byte last, where the
+//! inline case has its top bit set. The heap buffer is the one of a `String`, taken apart.
+
+use std::fmt;
+use std::mem::{ManuallyDrop, size_of};
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+/// The most text a `SmallString` holds without allocating: 23 bytes on 64-bit targets.
+pub const INLINE_CAPACITY: usize = 3 * size_of::<usize>() - 1;
+const INLINE_TAG: u8 = 0x80;
+
+/// A string with inline storage for short text.
+pub struct SmallString {
+    repr: Repr,
+}
+
+#[repr(C)]
+union Repr {
+    // the text, then `INLINE_TAG | len`
+    inline: [u8; INLINE_CAPACITY + 1],
+    heap: Heap,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Heap {
+    ptr: NonNull<u8>,
+    len: usize,
+    // `cap.to_le()`, so its last byte in memory is the most significant one
+    cap_le: usize,
+}
+
+const _: () = assert!(size_of::<SmallString>() == 3 * size_of::<usize>());
+
+// SAFETY: a heap `SmallString` owns its buffer like a `String` does.
+unsafe impl Send for SmallString {}
+// SAFETY: `&SmallString` only gives shared access to the text.
+unsafe impl Sync for SmallString {}
+
+impl SmallString {
+    /// An empty, inline, string.
+    pub const fn new() -> Self {
+        let mut inline = [0; INLINE_CAPACITY + 1];
+        inline[INLINE_CAPACITY] = INLINE_TAG;
+        SmallString {
+            repr: Repr { inline },
+        }
+    }
+
+    /// Whether the text is stored inline, without a heap allocation.
+    pub fn is_inline(&self) -> bool {
+        self.last_byte() & INLINE_TAG != 0
+    }
+
+    pub fn len(&self) -> usize {
+        if self.is_inline() {
+            (self.last_byte() & !INLINE_TAG) as usize
+        } else {
+            self.heap().len
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// [`INLINE_CAPACITY`] while inline, the size of the heap buffer after.
+    pub fn capacity(&self) -> usize {
+        if self.is_inline() {
+            INLINE_CAPACITY
+        } else {
+            usize::from_le(self.heap().cap_le)
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        let bytes = if self.is_inline() {
+            // SAFETY: the tag says the inline bytes are the active field.
+            let inline = unsafe { &self.repr.inline };
+            &inline[..self.len()]
+        } else {
+            let heap = self.heap();
+            // SAFETY: `ptr` points at a buffer with `len` initialized bytes, owned by `self`.
+            unsafe { std::slice::from_raw_parts(heap.ptr.as_ptr(), heap.len) }
+        };
+        // SAFETY: the bytes only ever come from `&str`s, appended whole.
+        unsafe { std::str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Appends `s`, moving the text to the heap when it stops fitting inline.
+    pub fn push_str(&mut self, s: &str) {
+        if self.is_inline() {
+            let len = self.len();
+            let new_len = len + s.len();
+            if new_len <= INLINE_CAPACITY {
+                // SAFETY: the tag says the inline bytes are the active field.
+                let inline = unsafe { &mut self.repr.inline };
+                inline[len..new_len].copy_from_slice(s.as_bytes());
+                inline[INLINE_CAPACITY] = INLINE_TAG | new_len as u8;
+                return;
+            }
+            // Spill: the inline text and `s` go to a new `String`.
+            let mut string = String::with_capacity(new_len);
+            string.push_str(self.as_str());
+            string.push_str(s);
+            self.set_heap(string);
+        } else {
+            self.with_string(|string| string.push_str(s));
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    fn last_byte(&self) -> u8 {
+        // SAFETY: every byte of `Repr` is initialized by either field, and the last byte is
+        // the tag (inline) or the most significant byte of `cap_le` (heap).
+        unsafe { self.repr.inline[INLINE_CAPACITY] }
+    }
+
+    fn heap(&self) -> Heap {
+        debug_assert!(!self.is_inline());
+        // SAFETY: only called when the tag says the heap parts are the active field.
+        unsafe { self.repr.heap }
+    }
+
+    // Takes `string` apart and keeps its buffer.
+    fn set_heap(&mut self, string: String) {
+        let mut string = ManuallyDrop::new(string);
+        let cap = string.capacity();
+        debug_assert!(cap <= isize::MAX as usize);
+        self.repr.heap = Heap {
+            // a `String` with text in it has a real, non-null, buffer
+            ptr: NonNull::new(string.as_mut_ptr()).unwrap(),
+            len: string.len(),
+            cap_le: cap.to_le(),
+        };
+    }
+
+    // Puts the heap buffer back together as a `String` for `f`, then takes it apart again.
+    fn with_string<R>(&mut self, f: impl FnOnce(&mut String) -> R) -> R {
+        let heap = self.heap();
+        // SAFETY: the parts come from a `String` taken apart by `set_heap`. `ManuallyDrop`
+        // keeps the buffer alive if `f` panics: `self` still owns it then.
+        let mut string = ManuallyDrop::new(unsafe {
+            String::from_raw_parts(heap.ptr.as_ptr(), heap.len, usize::from_le(heap.cap_le))
+        });
+        let result = f(&mut string);
+        self.set_heap(ManuallyDrop::into_inner(string));
+        result
+    }
+}
+
+impl Default for SmallString {
+    fn default() -> Self {
+        SmallString::new()
+    }
+}
+
+impl From<&str> for SmallString {
+    /// Inline when `s` is at most [`INLINE_CAPACITY`] bytes, a heap buffer of `s.len()` bytes
+    /// otherwise.
+    fn from(s: &str) -> Self {
+        let mut small = SmallString::new();
+        small.push_str(s);
+        small
+    }
+}
+
+impl Clone for SmallString {
+    fn clone(&self) -> Self {
+        SmallString::from(self.as_str())
+    }
+}
+
+impl Drop for SmallString {
+    fn drop(&mut self) {
+        if !self.is_inline() {
+            let heap = self.heap();
+            // SAFETY: the parts come from a `String` taken apart by `set_heap`, and are given
+            // back once.
+            drop(unsafe {
+                String::from_raw_parts(heap.ptr.as_ptr(), heap.len, usize::from_le(heap.cap_le))
+            });
+        }
+    }
+}
+
+impl Deref for SmallString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq<str> for SmallString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SmallString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
@@ -0,0 +1,353 @@
+//! Makes ownership visible: a [`Traced`] value logs where it is created, cloned, moved and
+//! dropped to a [`Timeline`], which reports the whole story once the demo is over.
+//!
+//! A move in Rust is a plain copy of bytes that no code can hook into, so the demos say when
+//! they move a value:
+//!
+//! ```ignore
+//! let timeline = Timeline::new();
+//! let s1 = timeline.track("s1", String::from("foo"));
+//! let s2 = s1.moved_to("s2");                                // let s2 = s1;
+//! let s3 = take_and_give_back(s2.moved_into("take_and_give_back")).returned_as("s3");
+//! ```
+//!
+//! Drops need no help, [`Traced`] implements `Drop`. The timeline keeps its events in a fixed
+//! array, so tracing a demo doesn't add heap allocations of its own.
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+
+use common::{Reporter, say};
+
+/// How many events a timeline keeps, the ones after are only counted.
+pub const CAPACITY: usize = 64;
+const MAX_SCOPES: usize = 8;
+
+/// Something that happened to a traced value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Created {
+        name: &'static str,
+    },
+    Cloned {
+        from: &'static str,
+        name: &'static str,
+    },
+    /// `let to = from;`
+    Moved {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// Passed by value to `function`.
+    MovedInto {
+        name: &'static str,
+        function: &'static str,
+    },
+    /// Given back by `function` and bound to `name`.
+    Returned {
+        from: &'static str,
+        function: &'static str,
+        name: &'static str,
+    },
+    /// Dropped by its owner, at the end of `scope`.
+    Dropped {
+        name: &'static str,
+        scope: &'static str,
+    },
+    Entered {
+        scope: &'static str,
+    },
+    Left {
+        scope: &'static str,
+    },
+}
+
+/// An event with where it happened. Drops have no location: they happen at the closing brace
+/// of the owner, which `Drop` can't see.
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    pub event: Event,
+    pub location: Option<&'static Location<'static>>,
+    pub depth: usize,
+}
+
+/// The events of one demo, in order.
+pub struct Timeline {
+    entries: RefCell<[Option<Entry>; CAPACITY]>,
+    len: Cell<usize>,
+    overflow: Cell<usize>,
+    scopes: RefCell<[&'static str; MAX_SCOPES]>,
+    depth: Cell<usize>,
+}
+
+/// A value whose ownership is logged to a [`Timeline`]. It derefs to the value, and prints as
+/// the value does.
+pub struct Traced<'t, T> {
+    value: T,
+    name: &'static str,
+    // the scope (or function) that owns the value now, and how deep it is
+    owner: &'static str,
+    depth: usize,
+    timeline: &'t Timeline,
+}
+
+/// Leaves the scope opened by [`Timeline::scope`] when dropped.
+pub struct ScopeGuard<'t> {
+    timeline: &'t Timeline,
+}
+
+impl Timeline {
+    pub const fn new() -> Self {
+        Timeline {
+            entries: RefCell::new([None; CAPACITY]),
+            len: Cell::new(0),
+            overflow: Cell::new(0),
+            scopes: RefCell::new(["the demo"; MAX_SCOPES]),
+            depth: Cell::new(0),
+        }
+    }
+
+    /// Starts tracing `value`, known as `name` from now on.
+    #[track_caller]
+    pub fn track<T>(&self, name: &'static str, value: T) -> Traced<'_, T> {
+        self.push(
+            Event::Created { name },
+            Some(Location::caller()),
+            self.depth.get(),
+        );
+        Traced {
+            value,
+            name,
+            owner: self.current_scope(),
+            depth: self.depth.get(),
+            timeline: self,
+        }
+    }
+
+    /// Opens a scope named `name` (usually a function) until the guard is dropped. Values
+    /// created in it are owned by it.
+    #[track_caller]
+    pub fn scope(&self, name: &'static str) -> ScopeGuard<'_> {
+        self.push(
+            Event::Entered { scope: name },
+            Some(Location::caller()),
+            self.depth.get(),
+        );
+        let depth = self.depth.get() + 1;
+        if depth < MAX_SCOPES {
+            self.scopes.borrow_mut()[depth] = name;
+        }
+        self.depth.set(depth);
+        ScopeGuard { timeline: self }
+    }
+
+    pub fn entries(&self) -> Vec<Entry> {
+        self.entries.borrow()[..self.len.get()]
+            .iter()
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    /// How many events didn't fit in the timeline.
+    pub fn overflow(&self) -> usize {
+        self.overflow.get()
+    }
+
+    /// Reports every event as a line: where it happened, then what happened, indented by how
+    /// deep the owner was.
+    pub fn report(&self, out: &mut dyn Reporter) {
+        say!(out, "Timeline:");
+        let entries = self.entries.borrow();
+        for entry in entries[..self.len.get()].iter().flatten() {
+            say!(out, "{}", entry);
+        }
+        if self.overflow.get() > 0 {
+            say!(out, "... and {} more events", self.overflow.get());
+        }
+    }
+
+    fn current_scope(&self) -> &'static str {
+        self.scopes.borrow()[self.depth.get().min(MAX_SCOPES - 1)]
+    }
+
+    fn push(&self, event: Event, location: Option<&'static Location<'static>>, depth: usize) {
+        let len = self.len.get();
+        if len == CAPACITY {
+            self.overflow.set(self.overflow.get() + 1);
+            return;
+        }
+        self.entries.borrow_mut()[len] = Some(Entry {
+            event,
+            location,
+            depth,
+        });
+        self.len.set(len + 1);
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ScopeGuard<'_> {
+    fn drop(&mut self) {
+        let timeline = self.timeline;
+        let scope = timeline.current_scope();
+        let depth = timeline.depth.get() - 1;
+        timeline.depth.set(depth);
+        timeline.push(Event::Left { scope }, None, depth);
+    }
+}
+
+impl<'t, T> Traced<'t, T> {
+    /// `let to = self;`: the value is now known as `to`.
+    #[track_caller]
+    pub fn moved_to(mut self, to: &'static str) -> Self {
+        self.log(
+            Event::Moved {
+                from: self.name,
+                to,
+            },
+            self.depth,
+        );
+        self.name = to;
+        self
+    }
+
+    /// The value is passed by value to `function`, which owns it from now on.
+    #[track_caller]
+    pub fn moved_into(mut self, function: &'static str) -> Self {
+        self.log(
+            Event::MovedInto {
+                name: self.name,
+                function,
+            },
+            self.depth,
+        );
+        self.owner = function;
+        self.depth += 1;
+        self
+    }
+
+    /// The function that owned the value gave it back, and it is bound to `name`.
+    #[track_caller]
+    pub fn returned_as(mut self, name: &'static str) -> Self {
+        let event = Event::Returned {
+            from: self.name,
+            function: self.owner,
+            name,
+        };
+        self.owner = self.timeline.current_scope();
+        self.depth = self.timeline.depth.get();
+        self.name = name;
+        self.log(event, self.depth);
+        self
+    }
+
+    /// A deep copy known as `name`, with its own heap data when `T` has any.
+    #[track_caller]
+    pub fn clone_as(&self, name: &'static str) -> Self
+    where
+        T: Clone,
+    {
+        let depth = self.timeline.depth.get();
+        self.log(
+            Event::Cloned {
+                from: self.name,
+                name,
+            },
+            depth,
+        );
+        Traced {
+            value: self.value.clone(),
+            name,
+            owner: self.timeline.current_scope(),
+            depth,
+            timeline: self.timeline,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[track_caller]
+    fn log(&self, event: Event, depth: usize) {
+        self.timeline.push(event, Some(Location::caller()), depth);
+    }
+}
+
+impl<T> Drop for Traced<'_, T> {
+    fn drop(&mut self) {
+        let event = Event::Dropped {
+            name: self.name,
+            scope: self.owner,
+        };
+        self.timeline.push(event, None, self.depth);
+    }
+}
+
+impl<T> Deref for Traced<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Traced<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Traced<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Traced<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Event::Created { name } => write!(f, "{name} created"),
+            Event::Cloned { from, name } => {
+                write!(f, "{name} cloned from {from}, with its own heap data")
+            }
+            Event::Moved { from, to } => {
+                write!(f, "{from} moved to {to}, {from} can't be used anymore")
+            }
+            Event::MovedInto { name, function } => write!(f, "{name} moved into {function}"),
+            Event::Returned {
+                from,
+                function,
+                name,
+            } => write!(f, "{from} returned by {function} as {name}"),
+            Event::Dropped { name, scope } => write!(f, "{name} dropped at the end of {scope}"),
+            Event::Entered { scope } => write!(f, "enter {scope}"),
+            Event::Left { scope } => write!(f, "leave {scope}"),
+        }
+    }
+}
+
+// `  172 |     s2 moved into take_and_give_back`
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(location) => write!(f, "{:>5} | ", location.line())?,
+            None => write!(f, "{:>5} | ", "")?,
+        }
+        write!(f, "{:indent$}{}", "", self.event, indent = self.depth * 2)
+    }
+}
@@ -0,0 +1,35 @@
+// The claims of the ownership notes, checked with `CountingAlloc`. One test only, the counters
+// are global and tests run on several threads.
+
+use common::NullReporter;
+use ownership_intro::alloc::CountingAlloc;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc::new();
+
+#[test]
+fn a_move_is_free_and_a_clone_is_not() {
+    let (s1, from) = ALLOC.measure(|| String::from("foo"));
+    assert_eq!((from.allocations, from.bytes_allocated), (1, 3));
+
+    let (s2, moved) = ALLOC.measure(|| s1);
+    assert_eq!(moved, Default::default(), "a move doesn't touch the heap");
+
+    let (mut s3, cloned) = ALLOC.measure(|| s2.clone());
+    assert_eq!((cloned.allocations, cloned.bytes_allocated), (1, 3));
+
+    let ((), pushed) = ALLOC.measure(|| s3.push_str(" bar"));
+    assert_eq!(pushed.allocations, 0);
+    assert_eq!(
+        pushed.reallocations, 1,
+        "the 3 bytes of \"foo\" can't hold \"foo bar\""
+    );
+
+    let ((), dropped) = ALLOC.measure(|| drop((s2, s3)));
+    assert_eq!(dropped.deallocations, 2);
+
+    let ((), demo) = ALLOC.measure(|| {
+        ownership_intro::move_demo_1(&mut NullReporter);
+    });
+    assert_eq!((demo.allocations, demo.deallocations), (1, 1));
+}
@@ -0,0 +1,67 @@
+// The interner stores each string once, the arena owns them and can be reset.
+
+use ownership_intro::arena::{Arena, CHUNK_SIZE};
+use ownership_intro::interner::Interner;
+
+#[test]
+fn interning_twice_gives_the_same_symbol_and_string() {
+    let arena = Arena::new();
+    let mut names = Interner::new(&arena);
+    let foo = names.intern("foo");
+    let hello = names.intern("hello");
+    assert_ne!(foo, hello);
+    assert_eq!(names.intern(&String::from("foo")), foo);
+    assert_eq!(names.len(), 2);
+    assert_eq!(arena.used(), "foohello".len());
+    let again = names.intern("foo");
+    assert!(std::ptr::eq(names.resolve(foo), names.resolve(again)));
+    assert_eq!(names.get("Foo"), None);
+}
+
+#[test]
+fn resolved_strings_outlive_the_interner() {
+    let arena = Arena::new();
+    let (foo, bar) = {
+        let mut names = Interner::new(&arena);
+        let foo = names.intern("foo string");
+        let bar = names.intern("bar string");
+        (names.resolve(foo), names.resolve(bar))
+    };
+    assert_eq!((foo, bar), ("foo string", "bar string"));
+}
+
+#[test]
+fn strings_stay_put_when_the_arena_grows() {
+    let arena = Arena::new();
+    let first = arena.alloc_str("Ioannis");
+    let long = "x".repeat(CHUNK_SIZE * 3);
+    for _ in 0..10 {
+        arena.alloc_str(&long);
+    }
+    assert!(arena.chunks() > 1);
+    assert_eq!(first, "Ioannis");
+    assert_eq!(arena.used(), 7 + 10 * long.len());
+}
+
+#[test]
+fn reset_forgets_the_strings_and_keeps_the_biggest_chunk() {
+    let mut arena = Arena::new();
+    {
+        let mut names = Interner::new(&arena);
+        for i in 0..200 {
+            names.intern(&format!("name {i}"));
+        }
+    }
+    let chunks = arena.chunks();
+    assert!(chunks > 1);
+
+    arena.reset();
+    assert_eq!((arena.used(), arena.chunks()), (0, 1));
+    let kept = arena.capacity();
+
+    let mut names = Interner::new(&arena);
+    let foo = names.intern("foo");
+    assert_eq!(foo.index(), 0);
+    assert_eq!(names.resolve(foo), "foo");
+    assert_eq!((arena.chunks(), arena.capacity()), (1, kept));
+}
@@ -0,0 +1,64 @@
+// `MyString` against what `String` does.
+
+use ownership_intro::my_string::{MIN_CAPACITY, MyString};
+
+#[test]
+fn new_does_not_allocate() {
+    let s = MyString::new();
+    assert_eq!((s.len(), s.capacity()), (0, 0));
+    assert_eq!(s, "");
+}
+
+#[test]
+fn from_allocates_exactly_the_text() {
+    let s = MyString::from("Foo");
+    assert_eq!((s.len(), s.capacity()), (3, 3));
+    assert_eq!(s, "Foo");
+}
+
+#[test]
+fn push_str_grows_like_string() {
+    let mut mine = MyString::new();
+    let mut std = String::new();
+    for word in ["a", "bc", "defghijk", "l", "mnopqrstuvwxyz0123456789"] {
+        mine.push_str(word);
+        std.push_str(word);
+        assert_eq!(mine.as_str(), std);
+        assert_eq!(mine.capacity(), std.capacity(), "after pushing {word:?}");
+    }
+    assert!(mine.capacity() >= MIN_CAPACITY);
+}
+
+#[test]
+fn push_keeps_utf8() {
+    let mut s = MyString::from("gr");
+    s.push('ü');
+    s.push_str("ße 🦀");
+    assert_eq!(s, "grüße 🦀");
+    assert_eq!(s.chars().count(), 7);
+}
+
+#[test]
+fn clone_is_a_deep_copy() {
+    let mut s = MyString::from("Foo");
+    let t = s.clone();
+    s.push_str(" Bar");
+    assert_eq!((s.as_str(), t.as_str()), ("Foo Bar", "Foo"));
+    assert_ne!(s.as_ptr(), t.as_ptr());
+}
+
+#[test]
+fn the_diagram_shows_the_triple_and_the_buffer() {
+    let mut s = MyString::from("Foo");
+    s.push_str(" Bar");
+    assert_eq!(
+        s.diagram(),
+        "\
+stack               heap
++-----+-----+       +---+---+---+---+---+---+---+---+
+| ptr |  *--+-----> | F | o | o |   | B | a | r | . |
+| len |   7 |       +---+---+---+---+---+---+---+---+
+| cap |   8 |         0   1   2   3   4   5   6   7
++-----+-----+"
+    );
+}
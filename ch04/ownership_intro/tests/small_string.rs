@@ -0,0 +1,68 @@
+// `SmallString` inline and on the heap, against what `String` does.
+
+use ownership_intro::small_string::{INLINE_CAPACITY, SmallString};
+
+#[test]
+fn is_three_words() {
+    assert_eq!(size_of::<SmallString>(), size_of::<String>());
+    assert_eq!(size_of::<SmallString>(), INLINE_CAPACITY + 1);
+}
+
+#[test]
+fn short_text_stays_inline() {
+    let s = SmallString::from("foo string");
+    assert!(s.is_inline());
+    assert_eq!((s.len(), s.capacity()), (10, INLINE_CAPACITY));
+    assert_eq!(s, "foo string");
+    assert!(SmallString::new().is_empty());
+}
+
+#[test]
+fn push_str_spills_past_the_inline_capacity() {
+    let fits = "x".repeat(INLINE_CAPACITY);
+    let mut s = SmallString::from(fits.as_str());
+    assert!(s.is_inline());
+
+    s.push('!');
+    assert!(!s.is_inline());
+    assert_eq!(s.len(), INLINE_CAPACITY + 1);
+    assert!(s.capacity() > INLINE_CAPACITY);
+    assert_eq!(s, format!("{fits}!").as_str());
+
+    for _ in 0..100 {
+        s.push_str("ab");
+    }
+    assert_eq!(s.len(), INLINE_CAPACITY + 201);
+    assert!(s.ends_with("abab"));
+}
+
+#[test]
+fn moves_and_clones_keep_the_text() {
+    let short = SmallString::from("Foo");
+    let moved = short;
+    assert_eq!(moved, "Foo");
+
+    let long = SmallString::from("a string that doesn't fit in 23 bytes");
+    let copy = long.clone();
+    let moved = long;
+    assert_eq!(moved, copy.as_str());
+    assert_ne!(moved.as_ptr(), copy.as_ptr());
+}
+
+#[test]
+fn prints_like_str() {
+    let mut s = SmallString::from("hello");
+    s.push_str(", world");
+    assert_eq!(format!("{s}"), "hello, world");
+    assert_eq!(format!("{s:?}"), "\"hello, world\"");
+    assert_eq!(format!("{s:>14}"), "  hello, world");
+}
+
+#[test]
+fn multibyte_text_crosses_over_whole() {
+    let mut s = SmallString::from("καλημέρα κόσμε");
+    assert!(!s.is_inline());
+    s.push('!');
+    assert_eq!(s, "καλημέρα κόσμε!");
+    assert_eq!(s.chars().count(), 15);
+}
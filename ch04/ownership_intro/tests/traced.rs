@@ -0,0 +1,87 @@
+// The stories `Timeline` tells about the values it traces.
+
+use common::NullReporter;
+use ownership_intro::traced::{CAPACITY, Event, Timeline};
+
+fn events(timeline: &Timeline) -> Vec<Event> {
+    timeline.entries().iter().map(|entry| entry.event).collect()
+}
+
+#[test]
+fn a_move_renames_and_the_last_owner_drops() {
+    let timeline = Timeline::new();
+    {
+        let _scope = timeline.scope("main");
+        let s1 = timeline.track("s1", String::from("foo"));
+        let _s2 = s1.moved_to("s2");
+    }
+    assert_eq!(
+        events(&timeline),
+        [
+            Event::Entered { scope: "main" },
+            Event::Created { name: "s1" },
+            Event::Moved {
+                from: "s1",
+                to: "s2"
+            },
+            Event::Dropped {
+                name: "s2",
+                scope: "main"
+            },
+            Event::Left { scope: "main" },
+        ]
+    );
+}
+
+#[test]
+fn a_value_given_to_a_function_is_dropped_there_unless_it_comes_back() {
+    let timeline = Timeline::new();
+    let taken = timeline.track("taken", String::from("a"));
+    let kept = timeline.track("kept", String::from("b"));
+    drop(taken.moved_into("consume"));
+    let back = ownership_intro::take_and_give_back(kept.moved_into("echo")).returned_as("back");
+    let copy = back.clone_as("copy");
+    drop((back, copy));
+
+    let entries = timeline.entries();
+    let dropped = entries.iter().find(|entry| {
+        entry.event
+            == Event::Dropped {
+                name: "taken",
+                scope: "consume",
+            }
+    });
+    assert_eq!(dropped.map(|entry| entry.depth), Some(1));
+    assert!(events(&timeline).contains(&Event::Returned {
+        from: "kept",
+        function: "echo",
+        name: "back"
+    }));
+    assert!(events(&timeline).contains(&Event::Cloned {
+        from: "back",
+        name: "copy"
+    }));
+    assert!(entries.iter().all(|entry| entry.location.is_some()
+        || matches!(entry.event, Event::Dropped { .. } | Event::Left { .. })));
+}
+
+#[test]
+fn the_location_is_the_callers() {
+    let timeline = Timeline::new();
+    let line = line!() + 1;
+    let _s = timeline.track("s", 1);
+    let location = timeline.entries()[0].location.unwrap();
+    assert_eq!((location.file(), location.line()), (file!(), line));
+}
+
+#[test]
+fn events_past_the_capacity_are_counted() {
+    let timeline = Timeline::new();
+    for _ in 0..CAPACITY {
+        timeline.track("n", 0);
+    }
+    assert_eq!(timeline.entries().len(), CAPACITY);
+    // every value was dropped right after it was created
+    assert_eq!(timeline.overflow(), CAPACITY);
+    timeline.report(&mut NullReporter);
+}
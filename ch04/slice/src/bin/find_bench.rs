@@ -0,0 +1,65 @@
+// The byte finders of `slice::find`, and `str::find`, timed on texts of a few sizes.
+//
+//     cargo run --release -p slice --bin find_bench
+//     cargo run --release -p slice --features sse2 --bin find_bench
+//
+// Each text is a log line without spaces, with one at its end, so every finder reads all of
+// it. Each finder reads about `BYTES` bytes per size, so the small sizes are called many
+// times: there the cost of a call counts, at the large sizes only the bytes per step do.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use slice::find;
+
+const SIZES: [usize; 7] = [8, 16, 64, 256, 4 * 1024, 64 * 1024, 1024 * 1024];
+const BYTES: usize = 64 * 1024 * 1024;
+
+type Finder = fn(&str) -> Option<usize>;
+
+const FINDERS: &[(&str, Finder)] = &[
+    ("naive", |text| find::naive(text.as_bytes(), b' ')),
+    ("str::find", |text| text.find(' ')),
+    ("swar", |text| find::swar(text.as_bytes(), b' ')),
+    #[cfg(all(feature = "sse2", target_arch = "x86_64"))]
+    ("sse2", |text| find::sse2(text.as_bytes(), b' ')),
+];
+
+fn main() {
+    print!("{:>10}", "bytes");
+    for (name, _) in FINDERS {
+        print!(" {name:>12}");
+    }
+    println!("   (GB/s)");
+    for size in SIZES {
+        let text = text(size);
+        print!("{size:>10}");
+        for (name, finder) in FINDERS {
+            assert_eq!(finder(&text), Some(size - 1), "{name} on {size} bytes");
+            let calls = (BYTES / size).max(1);
+            let elapsed = timed(calls, || finder(black_box(&text)));
+            let gigabytes_per_second = (calls * size) as f64 / elapsed.as_secs_f64() / 1e9;
+            print!(" {gigabytes_per_second:>12.2}");
+        }
+        println!();
+    }
+    if cfg!(not(feature = "sse2")) {
+        println!("(with `--features sse2` the SSE2 finder runs too)");
+    }
+}
+
+// `size` bytes of log lines without their spaces, and a space at the end.
+fn text(size: usize) -> String {
+    let line = "2024-05-01T12:00:00Z|GET|/index.html|200|0.004s|Mozilla/5.0\n";
+    let mut text: String = line.chars().cycle().take(size - 1).collect();
+    text.push(' ');
+    text
+}
+
+fn timed(calls: usize, mut call: impl FnMut() -> Option<usize>) -> Duration {
+    let start = Instant::now();
+    for _ in 0..calls {
+        black_box(call());
+    }
+    start.elapsed()
+}
@@ -0,0 +1,233 @@
+// Counts of text files, like `wc`, with the longest line and the most frequent words:
+//
+//     cargo run -p slice --bin textstats -- [--json] [--top N] [--chunk BYTES] [FILE...]
+//
+// Without files, or with `-`, it reads stdin. Files are read `--chunk` bytes at a time (64 KiB
+// by default), so they don't have to fit in memory, see `slice::text_stats`. `--json` prints
+// the same counts as one JSON object, for scripts. It exits with 1 when a file can't be read,
+// after counting the others.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use common::push_json_string;
+use slice::text_stats::{Counter, Stats};
+
+const USAGE: &str = "usage: textstats [--json] [--top N] [--chunk BYTES] [FILE...]";
+
+// A file once it is counted: its row of the table, or its JSON object, and its longest line.
+// Its words are only kept in the total, so there is one map of words however many files there are.
+struct Counted<'a> {
+    name: &'a str,
+    longest_line: Option<(usize, usize)>,
+    shown: String,
+}
+
+struct Options {
+    json: bool,
+    top: usize,
+    chunk: usize,
+    files: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let options = match options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let mut counted = Vec::new();
+    let mut total = Stats::default();
+    let mut failed = false;
+    for name in &options.files {
+        match count(name, options.chunk) {
+            Ok(stats) => {
+                let shown = if options.json {
+                    file_json(name, &stats, options.top)
+                } else {
+                    row(&stats, name)
+                };
+                counted.push(Counted {
+                    name,
+                    longest_line: stats.longest_line,
+                    shown,
+                });
+                total.add(stats);
+            }
+            Err(err) => {
+                eprintln!("textstats: {name}: {err}");
+                failed = true;
+            }
+        }
+    }
+    if options.json {
+        println!("{}", json(&counted, &total, options.top));
+    } else {
+        print!("{}", table(&counted, &total, options.top));
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        json: false,
+        top: 10,
+        chunk: 64 * 1024,
+        files: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => options.json = true,
+            "--top" | "--chunk" => {
+                let value = args.next().ok_or_else(|| format!("{arg} needs a number"))?;
+                let value: usize = value
+                    .parse()
+                    .map_err(|_| format!("{arg} needs a number, not `{value}`"))?;
+                if arg == "--top" {
+                    options.top = value;
+                } else if value == 0 {
+                    return Err(String::from("--chunk can't be 0"));
+                } else {
+                    options.chunk = value;
+                }
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+            _ => options.files.push(arg),
+        }
+    }
+    if options.files.is_empty() {
+        options.files.push(String::from("-"));
+    }
+    Ok(options)
+}
+
+// Reads `name`, or stdin for `-`, a chunk at a time: only one chunk is ever in memory.
+fn count(name: &str, chunk: usize) -> io::Result<Stats> {
+    let mut input: Box<dyn Read> = if name == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(File::open(name)?)
+    };
+    let mut buffer = vec![0; chunk];
+    let mut counter = Counter::new();
+    loop {
+        match input.read(&mut buffer) {
+            Ok(0) => return Ok(counter.finish()),
+            Ok(read) => counter.feed(&buffer[..read]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn table(counted: &[Counted], total: &Stats, top: usize) -> String {
+    let mut out = format!(
+        "{:>8} {:>8} {:>8} {:>8}  file\n",
+        "lines", "words", "chars", "bytes"
+    );
+    for file in counted {
+        out.push_str(&file.shown);
+    }
+    if counted.len() > 1 {
+        out.push_str(&row(total, "total"));
+    }
+    if let Some((name, (line, chars))) = longest_line(counted) {
+        writeln!(out, "longest line: {name}:{line}, {chars} chars").unwrap();
+    }
+    if top > 0 && total.distinct_words() > 0 {
+        writeln!(out, "most frequent of {} words:", total.distinct_words()).unwrap();
+        for (word, count) in total.top(top) {
+            writeln!(out, "{count:>8}  {word}").unwrap();
+        }
+    }
+    out
+}
+
+fn row(stats: &Stats, name: &str) -> String {
+    let (lines, words, chars, bytes) = (stats.lines, stats.words, stats.chars, stats.bytes);
+    format!("{lines:>8} {words:>8} {chars:>8} {bytes:>8}  {name}\n")
+}
+
+// One object, a line per file:
+//
+//     {"files": [
+//       {"name": "a.txt", "lines": 1, ..., "longest_line": {"line": 1, "chars": 5}, ...}
+//     ], "total": {"lines": 1, ..., "longest_line": {"file": "a.txt", ...}, ...}}
+fn json(counted: &[Counted], total: &Stats, top: usize) -> String {
+    let files: Vec<&str> = counted.iter().map(|file| file.shown.as_str()).collect();
+    let longest = match longest_line(counted) {
+        Some((name, (line, chars))) => format!(
+            "{{\"file\": {}, \"line\": {line}, \"chars\": {chars}}}",
+            string(name)
+        ),
+        None => String::from("null"),
+    };
+    let total = format!(
+        "{{{}, \"longest_line\": {longest}, \"distinct_words\": {}, \"top_words\": {}}}",
+        counts(total),
+        total.distinct_words(),
+        top_words(total, top)
+    );
+    format!(
+        "{{\"files\": [\n  {}\n], \"total\": {total}}}",
+        files.join(",\n  ")
+    )
+}
+
+fn file_json(name: &str, stats: &Stats, top: usize) -> String {
+    let longest = match stats.longest_line {
+        Some((line, chars)) => format!("{{\"line\": {line}, \"chars\": {chars}}}"),
+        None => String::from("null"),
+    };
+    format!(
+        "{{\"name\": {}, {}, \"longest_line\": {longest}, \"top_words\": {}}}",
+        string(name),
+        counts(stats),
+        top_words(stats, top)
+    )
+}
+
+// The first of the longest lines of all the files, and the file it is in.
+fn longest_line<'a>(counted: &[Counted<'a>]) -> Option<(&'a str, (usize, usize))> {
+    counted
+        .iter()
+        .filter_map(|file| Some((file.name, file.longest_line?)))
+        .reduce(|longest, next| {
+            if next.1.1 > longest.1.1 {
+                next
+            } else {
+                longest
+            }
+        })
+}
+
+fn counts(stats: &Stats) -> String {
+    format!(
+        "\"lines\": {}, \"words\": {}, \"chars\": {}, \"bytes\": {}",
+        stats.lines, stats.words, stats.chars, stats.bytes
+    )
+}
+
+fn top_words(stats: &Stats, top: usize) -> String {
+    let words: Vec<String> = stats
+        .top(top)
+        .into_iter()
+        .map(|(word, count)| format!("{{\"word\": {}, \"count\": {count}}}", string(word)))
+        .collect();
+    format!("[{}]", words.join(", "))
+}
+
+// `text` as a JSON string.
+fn string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    push_json_string(&mut out, text);
+    out
+}
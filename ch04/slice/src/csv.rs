@@ -0,0 +1,311 @@
+//! CSV records whose fields are slices of the text, and a writer of them.
+//!
+//! The records are the ones of RFC 4180: fields separated by `,`, records by `\r\n` or `\n`,
+//! and a field in `"` can have `,`, line breaks, and `""` for a `"`. A field is a
+//! `Cow::Borrowed` slice of the text, like the words of [`crate::words`], unless it has `""`:
+//! then its text isn't anywhere in the input, and only that field is a `Cow::Owned` copy.
+//!
+//! ```
+//! use std::borrow::Cow;
+//! use slice::csv::reader;
+//!
+//! let mut records = reader("name,quote\r\nAda,\"said \"\"hi\"\"\"\r\n").with_headers()?;
+//! assert_eq!(records.column("quote"), Some(1));
+//! let record = records.next().unwrap()?;
+//! assert!(matches!(record.fields[0], Cow::Borrowed("Ada")));
+//! assert!(matches!(&record.fields[1], Cow::Owned(quote) if quote == "said \"hi\""));
+//!
+//! let error = reader("a,b\n1,\"2\"3\n").nth(1).unwrap().unwrap_err();
+//! assert_eq!(error.to_string(), "line 2, column 6: expected `,` or a line break after `\"`");
+//! # Ok::<(), slice::csv::Error>(())
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// The fields of a record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub fields: Vec<Cow<'a, str>>,
+    /// The line the record starts at, from 1.
+    pub line: usize,
+}
+
+/// A malformed record, and where in the text it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// From 1, counting the line breaks inside quoted fields.
+    pub line: usize,
+    /// In `char`s from 1.
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A `"` in a field that doesn't start with one.
+    QuoteInField,
+    /// A quoted field without its closing `"`, at the opening one.
+    UnterminatedQuote,
+    /// Something else than `,` or a line break after the closing `"` of a field.
+    TextAfterQuote,
+    /// A record without as many fields as the first one, at its start.
+    FieldCount { expected: usize, found: usize },
+}
+
+/// The records of a CSV text, see [`reader`].
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    text: &'a str,
+    // where the next record starts, and its line
+    pos: usize,
+    line: usize,
+    headers: Option<Record<'a>>,
+    // the number of fields of every record: the one of the first
+    width: Option<usize>,
+    failed: bool,
+}
+
+/// The records of `text`. Blank lines between them are skipped, and the first error ends them.
+pub fn reader(text: &str) -> Reader<'_> {
+    Reader {
+        text,
+        pos: 0,
+        line: 1,
+        headers: None,
+        width: None,
+        failed: false,
+    }
+}
+
+impl<'a> Reader<'a> {
+    /// Reads the first record as the names of the columns, see [`Reader::column`].
+    pub fn with_headers(mut self) -> Result<Self, Error> {
+        self.headers = self.next().transpose()?;
+        Ok(self)
+    }
+
+    pub fn headers(&self) -> Option<&Record<'a>> {
+        self.headers.as_ref()
+    }
+
+    /// The index of the field named `name` by the headers.
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.headers
+            .as_ref()?
+            .iter()
+            .position(|header| header == name)
+    }
+
+    fn record(&mut self) -> Result<Option<Record<'a>>, Error> {
+        let bytes = self.text.as_bytes();
+        loop {
+            match bytes.get(self.pos..self.pos + 2) {
+                Some(b"\r\n") => self.pos += 2,
+                _ if bytes.get(self.pos) == Some(&b'\n') => self.pos += 1,
+                _ if self.pos == bytes.len() => return Ok(None),
+                _ => break,
+            }
+            self.line += 1;
+        }
+        let (start, line) = (self.pos, self.line);
+        let mut fields = Vec::new();
+        loop {
+            fields.push(self.field(start, line)?);
+            match bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                // `field` only stops at the `\r` of a `\r\n`
+                Some(b'\r') => {
+                    self.pos += 2;
+                    break;
+                }
+                Some(b'\n') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => break,
+            }
+        }
+        self.line += self.text[start..self.pos].matches('\n').count();
+        let expected = *self.width.get_or_insert(fields.len());
+        if fields.len() != expected {
+            let found = fields.len();
+            return Err(self.error(
+                start,
+                line,
+                start,
+                ErrorKind::FieldCount { expected, found },
+            ));
+        }
+        Ok(Some(Record { fields, line }))
+    }
+
+    // The field at `self.pos`, of the record at `start` on `line`, leaving `self.pos` after it.
+    // `,`, `"`, `\r` and `\n` are ASCII, never part of another `char`, so the bytes can be
+    // searched for them and the text sliced where they are.
+    fn field(&mut self, start: usize, line: usize) -> Result<Cow<'a, str>, Error> {
+        let (text, bytes) = (self.text, self.text.as_bytes());
+        let from = self.pos;
+        let ends = |at: usize| match bytes.get(at) {
+            None | Some(b',' | b'\n') => true,
+            Some(b'\r') => bytes.get(at + 1) == Some(&b'\n'),
+            _ => false,
+        };
+        if bytes.get(from) != Some(&b'"') {
+            let mut end = from;
+            while !ends(end) {
+                if bytes[end] == b'"' {
+                    return Err(self.error(start, line, end, ErrorKind::QuoteInField));
+                }
+                end += 1;
+            }
+            self.pos = end;
+            return Ok(Cow::Borrowed(&text[from..end]));
+        }
+        let mut end = from + 1;
+        let mut escaped = false;
+        loop {
+            match bytes.get(end) {
+                None => return Err(self.error(start, line, from, ErrorKind::UnterminatedQuote)),
+                Some(b'"') if bytes.get(end + 1) == Some(&b'"') => {
+                    escaped = true;
+                    end += 2;
+                }
+                Some(b'"') => break,
+                _ => end += 1,
+            }
+        }
+        self.pos = end + 1;
+        if !ends(self.pos) {
+            return Err(self.error(start, line, self.pos, ErrorKind::TextAfterQuote));
+        }
+        let field = &text[from + 1..end];
+        Ok(if escaped {
+            Cow::Owned(field.replace("\"\"", "\""))
+        } else {
+            Cow::Borrowed(field)
+        })
+    }
+
+    // An error at `at`, in the record at `start` on `line`: records start at the start of a line.
+    fn error(&self, start: usize, line: usize, at: usize, kind: ErrorKind) -> Error {
+        let before = &self.text[start..at];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Error {
+            line: line + before.matches('\n').count(),
+            column: before[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+}
+
+impl<'a> Iterator for Reader<'a> {
+    type Item = Result<Record<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let record = self.record().transpose();
+        self.failed = matches!(record, Some(Err(_)));
+        record
+    }
+}
+
+impl FusedIterator for Reader<'_> {}
+
+impl<'a> Record<'a> {
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.fields.get(index).map(|field| field.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|field| field.as_ref())
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::QuoteInField => write!(f, "`\"` in a field that isn't in `\"`"),
+            ErrorKind::UnterminatedQuote => write!(f, "`\"` without a closing `\"`"),
+            ErrorKind::TextAfterQuote => write!(f, "expected `,` or a line break after `\"`"),
+            ErrorKind::FieldCount { expected, found } => {
+                write!(
+                    f,
+                    "expected {expected} fields, like the first record, found {found}"
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Writes records, each followed by `\r\n`, quoting the fields that need it.
+#[derive(Debug)]
+pub struct Writer<W> {
+    out: W,
+}
+
+impl<W: fmt::Write> Writer<W> {
+    pub fn new(out: W) -> Self {
+        Writer { out }
+    }
+
+    pub fn write_record<I>(&mut self, fields: I) -> fmt::Result
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut fields = fields.into_iter().peekable();
+        let mut first = true;
+        while let Some(field) = fields.next() {
+            let field = field.as_ref();
+            if !first {
+                self.out.write_char(',')?;
+            }
+            if first && field.is_empty() && fields.peek().is_none() {
+                // a lone empty field unquoted would be a blank line, which isn't a record
+                self.out.write_str("\"\"")?;
+            } else {
+                self.out.write_str(&quote(field))?;
+            }
+            first = false;
+        }
+        self.out.write_str("\r\n")
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+/// `field` as it is written in a record: as it is, or in `"` with its `"` doubled when it has
+/// `,`, `"` or a line break.
+pub fn quote(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
@@ -0,0 +1,108 @@
+//! Finding a byte, like the book's `first_word` finds `b' '`, more than one byte per step.
+//!
+//! - [`naive`]: the book's loop, `as_bytes().iter().enumerate()`, one byte and one branch at a
+//!   time.
+//! - [`swar`]: "SIMD within a register", 8 bytes at a time as one `u64`. The bytes equal to the
+//!   needle are made zero by a xor, and a subtraction finds the zero bytes of all 8 at once.
+//! - `sse2`, only with the `sse2` feature on x86_64: 16 bytes at a time, compared by one
+//!   instruction. Every x86_64 CPU has SSE2, so it needs no check at runtime.
+//! - [`find_byte`]: the fastest of these the build has.
+//!
+//! All of them find the first match, and agree with [`naive`] on every input. `cargo run
+//! --release -p slice --bin find_bench` times them, and `str::find`, on texts of a few sizes.
+//!
+//! ```
+//! use slice::find::{find_byte, first_word_bytes, naive, swar};
+//!
+//! let log = b"2024-05-01T12:00:00Z GET /index.html 200";
+//! assert_eq!(find_byte(log, b' '), Some(20));
+//! assert_eq!(swar(log, b' '), naive(log, b' '));
+//! assert_eq!(first_word_bytes("GET /index.html"), "GET");
+//! ```
+
+/// The book's [`first_word`](crate::first_word), `b' '` and all, with the space found by [`find_byte`]: the text up
+/// to the first space, or all of it.
+pub fn first_word_bytes(text: &str) -> &str {
+    // `b' '` is ASCII, never part of another `char`, so where it is is a `char` boundary
+    match find_byte(text.as_bytes(), b' ') {
+        Some(space) => &text[..space],
+        None => text,
+    }
+}
+
+/// The index of the first `needle` of `haystack`, by the fastest way of this build.
+pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    #[cfg(all(feature = "sse2", target_arch = "x86_64"))]
+    return sse2(haystack, needle);
+    #[cfg(not(all(feature = "sse2", target_arch = "x86_64")))]
+    swar(haystack, needle)
+}
+
+/// The index of the first `needle` of `haystack`, a byte at a time.
+pub fn naive(haystack: &[u8], needle: u8) -> Option<usize> {
+    for (i, &byte) in haystack.iter().enumerate() {
+        if byte == needle {
+            return Some(i);
+        }
+    }
+    None
+}
+
+// 0x01 and 0x80 in each byte of a `u64`.
+const LOW_BITS: u64 = u64::from_ne_bytes([0x01; 8]);
+const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; 8]);
+
+/// The index of the first `needle` of `haystack`, 8 bytes at a time.
+pub fn swar(haystack: &[u8], needle: u8) -> Option<usize> {
+    let needles = LOW_BITS * u64::from(needle);
+    let mut words = haystack.chunks_exact(8);
+    for (i, word) in words.by_ref().enumerate() {
+        // little-endian, so the first byte is the lowest one, the first a `trailing_zeros` finds
+        let word = u64::from_le_bytes(word.try_into().unwrap());
+        if let Some(byte) = first_zero_byte(word ^ needles) {
+            return Some(i * 8 + byte);
+        }
+    }
+    let rest = words.remainder();
+    naive(rest, needle).map(|i| haystack.len() - rest.len() + i)
+}
+
+// The index of the lowest zero byte of `word`. Subtracting 1 from each byte only sets the high
+// bit of the ones that were 0, or above 0x80; `!word` drops the second. A byte that was 0 also
+// borrows from the one above, which can then look like a 0 too, but only above a real one, so
+// the lowest flagged byte is always right.
+fn first_zero_byte(word: u64) -> Option<usize> {
+    let zeros = word.wrapping_sub(LOW_BITS) & !word & HIGH_BITS;
+    (zeros != 0).then(|| zeros.trailing_zeros() as usize / 8)
+}
+
+/// The index of the first `needle` of `haystack`, 16 bytes at a time with SSE2.
+#[cfg(all(feature = "sse2", target_arch = "x86_64"))]
+pub fn sse2(haystack: &[u8], needle: u8) -> Option<usize> {
+    // SAFETY: `with_sse2` only needs the SSE2 instructions, which every x86_64 CPU has
+    unsafe { with_sse2(haystack, needle) }
+}
+
+// `sse2`, compiled for CPUs with SSE2, which makes its intrinsics safe to call.
+#[cfg(all(feature = "sse2", target_arch = "x86_64"))]
+#[target_feature(enable = "sse2")]
+fn with_sse2(haystack: &[u8], needle: u8) -> Option<usize> {
+    use std::arch::x86_64::{
+        __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_set1_epi8,
+    };
+
+    let needles = _mm_set1_epi8(needle as i8);
+    let mut blocks = haystack.chunks_exact(16);
+    for (i, block) in blocks.by_ref().enumerate() {
+        // SAFETY: `block` has 16 bytes, the size of an `__m128i`, and `loadu` reads them
+        // without requiring them to be aligned
+        let bytes = unsafe { _mm_loadu_si128(block.as_ptr().cast::<__m128i>()) };
+        // a bit per byte, set where the byte is the needle, the first byte's the lowest
+        let matches = _mm_movemask_epi8(_mm_cmpeq_epi8(bytes, needles));
+        if matches != 0 {
+            return Some(i * 16 + matches.trailing_zeros() as usize);
+        }
+    }
+    let rest = blocks.remainder();
+    swar(rest, needle).map(|i| haystack.len() - rest.len() + i)
+}
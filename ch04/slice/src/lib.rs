@@ -0,0 +1,199 @@
+/*
+Rust’s standard library includes a number of very useful data structures called collections.
+Most other data types represent one specific value, but collections can contain multiple values.
+Unlike the built-in array and tuple types, the data that these **collections point to is stored on the heap**,
+which means the amount of data does not need to be known at compile time and can grow or shrink as the program runs.
+Each kind of collection has different capabilities and costs, and choosing an appropriate one for your current situation is a skill you’ll develop over time.
+
+--> A vector allows you to store a variable number of values next to each other.
+--> A string is a collection of characters. We’ve mentioned the String type previously, but in this chapter we’ll talk about it in depth.
+--> A hash map allows you to associate a value with a specific key. It’s a particular implementation of the more general data structure called a map.
+
+**Slices** let you reference a contiguous sequence of elements in a collection rather than the whole collection.
+A slice is a kind of reference, so it does not have ownership.
+
+
+
+Recall from the borrowing rules that if we have an immutable reference to something, we cannot also take a mutable reference.
+Because clear needs to truncate the String, it needs to get a mutable reference. The println! after the call to clear uses the reference in word,
+so the immutable reference must still be active at that point. Rust disallows the mutable reference in clear and the immutable reference 
+in word from existing at the same time, and compilation fails. Not only has Rust made our API easier to use, but it has also eliminated 
+an entire class of errors at compile time!
+
+
+Summary
+The concepts of ownership, borrowing, and slices ensure memory safety in Rust programs at compile time.
+ 
+ The Rust language gives you control over your memory usage in the same way as other systems programming languages,
+but having the owner of data automatically clean up that data when the owner goes out of scope means you don’t have to write and debug extra code to get this control.
+
+Ownership affects how lots of other parts of Rust work, so we’ll talk about these concepts further throughout the rest of the book.
+Let’s move on to Chapter 5 and look at grouping pieces of data together in a struct.
+
+
+Note:
+  To create a slice we always have to use [..] notation same like Python
+*/
+
+
+pub mod csv;
+pub mod find;
+pub mod text_stats;
+pub mod words;
+
+use std::borrow::Cow;
+
+use common::{Chapter, Demo, Reporter, say};
+use words::{last_word, nth_word, words};
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch04/slice",
+    package: "slice",
+    description: "String and array slices",
+    banner: None,
+    demos: &[
+        Demo {
+            name: "first_word_demo",
+            description: "Return the first word of a sentence as a slice",
+            run: |out| {
+                first_word_demo(out);
+            },
+        },
+        Demo {
+            name: "words_demo",
+            description: "Split words at any Unicode whitespace and at punctuation",
+            run: |out| {
+                words_demo(out);
+            },
+        },
+        Demo {
+            name: "csv_demo",
+            description: "Read CSV records whose fields borrow the text, and write them back",
+            run: |out| {
+                csv_demo(out);
+            },
+        },
+        Demo {
+            name: "mutable_slice_demo",
+            description: "Negate the numbers of an array through a `&mut [i32]`",
+            run: |out| {
+                mutable_slice_demo(out);
+            },
+        },
+    ],
+};
+
+/// Finds the first word of a greeting, returns an owned copy because the slice can't outlive `greet`.
+pub fn first_word_demo(out: &mut dyn Reporter) -> String {
+    let greet = String::from("Hello, world!");
+      let first = first_word(&greet);
+      if first.len() < greet.len() {
+          say!(out, "found first empty space at index: `{}`", first.len());
+      }
+      say!(out, "First word is: `{}`", first);
+    // if for some reasons we cleanup the string
+    // also the slice will not be valid and 
+    // we are going to have a compiler error
+    // greet.clear();  (tests/compile_fail/clear_while_borrowed.rs)
+    let first_again = first_word_improved(&greet);
+     if first_again.len() < greet.len() {
+         say!(out, "found first empty space at index: `{}`", first_again.len());
+     }
+     say!(out, "First word is: `{}`", first_again);
+     first_again.to_string()
+}
+
+/// The words of texts the book's `first_word` gets wrong, and of the greeting without its
+/// punctuation, returns the first word of the greeting.
+pub fn words_demo(out: &mut dyn Reporter) -> String {
+    let greet = String::from("Hello, world!");
+    // `words::first_word` takes a `&str`: `&greet`, a `&String`, coerces to one
+    let first = words::first_word(&greet).unwrap_or("");
+    say!(out, "First word is: `{}`", first);
+    // the book's version only stopped at b' ', these got the wrong first word
+    for text in ["\t  leading whitespace", "tab\tseparated", "non\u{a0}breaking space", "line\nbreak", "  "] {
+        say!(out, "{:?}: first {:?}, second {:?}, last {:?}", text, words::first_word(text), nth_word(text, 1), last_word(text));
+    }
+    let spans: Vec<String> = words(&greet)
+        .split_punctuation()
+        .map(|word| format!("`{}` {:?}", word.text, word.span))
+        .collect();
+    say!(out, "Words of `{}` without the punctuation: {}", greet, spans.join(", "));
+    first.to_string()
+}
+
+/// Reads a CSV text, says which fields are slices of it, and writes the records back.
+pub fn csv_demo(out: &mut dyn Reporter) -> String {
+    let text = "city,country,note\r\nAthens,Greece,\"capital, since 1834\"\r\nLyon,France,\"called \"\"Lugdunum\"\"\"\r\n";
+    let mut records = csv::reader(text).with_headers().expect("the headers are well formed");
+    let note = records.column("note").unwrap();
+    let mut writer = csv::Writer::new(String::new());
+    writer.write_record(records.headers().unwrap().iter()).unwrap();
+    for record in records.by_ref() {
+        let record = record.expect("the records are well formed");
+        // only the field with `""` had to be unescaped into a `String`, the others point into `text`
+        let fields: Vec<String> = record
+            .fields
+            .iter()
+            .map(|field| match field {
+                Cow::Borrowed(slice) => format!("{:?} borrowed", slice),
+                Cow::Owned(string) => format!("{:?} owned", string),
+            })
+            .collect();
+        say!(out, "line {}: {}, note: {:?}", record.line, fields.join(", "), record.get(note));
+        writer.write_record(record.iter()).unwrap();
+    }
+    for bad in ["a,b\n1,2,3\n", "a,\"b\nc", "a,b\"c\n"] {
+        say!(out, "{:?}: {}", bad, csv::reader(bad).find_map(Result::err).unwrap());
+    }
+    let written = writer.into_inner();
+    say!(out, "Written back: {:?}", written);
+    written
+}
+
+/// Negates an array through a mutable slice and returns the array.
+pub fn mutable_slice_demo(out: &mut dyn Reporter) -> [i32; 6] {
+     let mut  numbers : [i32;6] = [0,1,2,3,4,5];
+      mutable_slice(out, &mut numbers[..]);
+      say!(out, "numbers now are: {:?}", numbers);
+      numbers
+}
+
+
+
+// helper function to find the first word in a sentence
+/// The part of `s` before the first space, or all of `s` when there is none.
+pub fn first_word(s : &String) ->  &str{
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate(){
+        if item == b' '{
+            return &s[0..i];
+            }
+        }
+        s
+
+}
+
+// This is an improvement because we can pass &str (immutable slice) or an immutable reference of String
+// The type of s here is &str: it’s a slice pointing to that specific point of the binary.
+//  This is also why string literals are immutable; &str is an immutable reference.
+/// Same as [`first_word`] but works on any `&str`, `&String` coerces to it.
+pub fn first_word_improved(s : &str) ->  &str{
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate(){
+        if item == b' '{
+            return &s[0..i];
+            }
+        }
+        s
+
+}
+
+/// Negates every number of the slice in place.
+pub fn mutable_slice(out: &mut dyn Reporter, numbers: &mut [i32]){
+    for (index, number) in numbers.iter_mut().enumerate(){
+        say!(out, "got number: {} and index: {}", number, index);
+         *number = - *number;
+
+    }
+}
\ No newline at end of file
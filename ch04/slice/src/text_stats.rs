@@ -0,0 +1,291 @@
+//! Counts of a text, like `wc`, from chunks of bytes: lines, words, `char`s, bytes, the longest
+//! line, and how often each word is in it.
+//!
+//! A [`Counter`] is fed the text a chunk at a time, so a file doesn't have to fit in memory.
+//! A chunk can end in the middle of a word, or of a `char`: the part of it after the last
+//! whitespace is kept until the next chunk completes it, so what is kept is at most one word,
+//! and never more than [`MAX_WORD`] bytes and a chunk. Every other word is counted as a slice of
+//! the chunk, and only allocated the first time it is seen, as a key of the counts. Bytes that
+//! aren't UTF-8 count as one `�` each time, like `String::from_utf8_lossy` would make them.
+//!
+//! ```
+//! use slice::text_stats::Counter;
+//!
+//! let mut counter = Counter::new();
+//! counter.feed("the cat saw th".as_bytes());
+//! counter.feed("e other cat\n".as_bytes());
+//! let stats = counter.finish();
+//! assert_eq!((stats.lines, stats.words, stats.chars), (1, 6, 26));
+//! assert_eq!(stats.top(2), [("cat", 2), ("the", 2)]);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::words::words;
+
+/// How long a word can be, in bytes, and still be in the counts of words: a longer one, like a
+/// line of base64, is counted as a word and its `char`s too, but not kept, so a text without
+/// whitespace doesn't have to fit in memory either.
+pub const MAX_WORD: usize = 4096;
+
+/// What a text has, see [`Counter`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// `\n`s, like `wc -l`: a last line without one isn't counted.
+    pub lines: usize,
+    /// Runs of anything but whitespace, like `wc -w`.
+    pub words: usize,
+    pub chars: usize,
+    pub bytes: usize,
+    /// The longest line, as (its number from 1, its length in `char`s without the `\n`), the
+    /// first one when several are as long.
+    pub longest_line: Option<(usize, usize)>,
+    // lowercase words, split at punctuation too, and how many times each is in the text
+    counts: HashMap<String, usize>,
+}
+
+/// Counts a text fed in chunks, see the module.
+#[derive(Debug, Default)]
+pub struct Counter {
+    stats: Stats,
+    // the end of the last chunk that may go on in the next one: a word, a `char`, or both
+    carry: Vec<u8>,
+    // the carry is the end of a word longer than `MAX_WORD`, whose start is counted already
+    long_word: bool,
+    // the `char`s of the line so far
+    line: usize,
+    // a word's lowercase, made here instead of in a new `String` each time
+    lowercase: String,
+}
+
+impl Counter {
+    pub fn new() -> Self {
+        Counter::default()
+    }
+
+    /// Counts `chunk`, which goes on from where the last one ended.
+    pub fn feed(&mut self, mut chunk: &[u8]) {
+        self.stats.bytes += chunk.len();
+        if !self.carry.is_empty() || self.long_word {
+            // what is carried ends at the first whitespace of this chunk, or goes on to the next
+            let Some(end) = first_whitespace(chunk) else {
+                self.carry.extend_from_slice(chunk);
+                self.bound_carry();
+                return;
+            };
+            let mut carry = std::mem::take(&mut self.carry);
+            carry.extend_from_slice(&chunk[..end]);
+            self.count_carried(&carry);
+            carry.clear();
+            // kept for the next time, with its capacity
+            self.carry = carry;
+            chunk = &chunk[end..];
+        }
+        match last_whitespace_end(chunk) {
+            Some(end) => {
+                self.count(&chunk[..end]);
+                self.carry.extend_from_slice(&chunk[end..]);
+            }
+            None => self.carry.extend_from_slice(chunk),
+        }
+        self.bound_carry();
+    }
+
+    /// How many bytes are kept for the next chunk: never more than [`MAX_WORD`] and the last chunk.
+    pub fn carried(&self) -> usize {
+        self.carry.len()
+    }
+
+    /// The counts, once the whole text was fed.
+    pub fn finish(mut self) -> Stats {
+        let carry = std::mem::take(&mut self.carry);
+        self.count_carried(&carry);
+        self.end_line(false);
+        self.stats
+    }
+
+    // Counts what was carried, which ends where a word does: the end of a long word first, if
+    // it is one.
+    fn count_carried(&mut self, bytes: &[u8]) {
+        if !self.long_word {
+            return self.count(bytes);
+        }
+        let end = first_whitespace(bytes).unwrap_or(bytes.len());
+        self.count_chars(&bytes[..end]);
+        self.long_word = false;
+        self.count(&bytes[end..]);
+    }
+
+    // Once the carry is longer than `MAX_WORD`, counts it as the start of a long word, and only
+    // keeps the bytes of a `char` the next chunk may end.
+    fn bound_carry(&mut self) {
+        if self.carry.len() <= MAX_WORD && !self.long_word {
+            return;
+        }
+        let mut carry = std::mem::take(&mut self.carry);
+        // a whitespace `char` the last chunk ended in the middle of is only whole in the carry
+        let start = match last_whitespace_end(&carry) {
+            Some(end) => {
+                self.count_carried(&carry[..end]);
+                end
+            }
+            None => 0,
+        };
+        let mut split = start;
+        if self.long_word || carry.len() - start > MAX_WORD {
+            if !self.long_word {
+                self.long_word = true;
+                self.stats.words += 1;
+            }
+            // the last byte that starts a `char` of more than one byte, in the last 3
+            split = (carry.len().saturating_sub(3).max(start)..carry.len())
+                .rev()
+                .find(|&at| carry[at] >= 0xC0)
+                .unwrap_or(carry.len());
+            self.count_chars(&carry[start..split]);
+        }
+        carry.drain(..split);
+        self.carry = carry;
+    }
+
+    // Counts the `char`s of part of a word longer than `MAX_WORD`, as they come.
+    fn count_chars(&mut self, bytes: &[u8]) {
+        let chars = String::from_utf8_lossy(bytes).chars().count();
+        self.line += chars;
+        self.stats.chars += chars;
+    }
+
+    // Counts `bytes`, which end where a word does. Only bytes that aren't UTF-8 are copied, to
+    // replace them.
+    fn count(&mut self, bytes: &[u8]) {
+        let text = String::from_utf8_lossy(bytes);
+        self.count_text(&text);
+    }
+
+    fn count_text(&mut self, text: &str) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.end_line(true);
+            }
+            let chars = line.chars().count();
+            self.line += chars;
+            self.stats.chars += chars;
+        }
+        for word in words(text) {
+            self.stats.words += 1;
+            if word.text.len() > MAX_WORD {
+                // counted, but not kept, like the ones `bound_carry` didn't keep
+                continue;
+            }
+            for word in words(word.text).split_punctuation() {
+                self.count_word(word.text);
+            }
+        }
+    }
+
+    fn count_word(&mut self, word: &str) {
+        let word = if word.chars().any(char::is_uppercase) {
+            self.lowercase.clear();
+            self.lowercase
+                .extend(word.chars().flat_map(char::to_lowercase));
+            self.lowercase.as_str()
+        } else {
+            word
+        };
+        // a word seen before is counted without copying it
+        match self.stats.counts.get_mut(word) {
+            Some(count) => *count += 1,
+            None => {
+                self.stats.counts.insert(word.to_string(), 1);
+            }
+        }
+    }
+
+    fn end_line(&mut self, newline: bool) {
+        if newline {
+            self.stats.lines += 1;
+            self.stats.chars += 1;
+        }
+        let number = self.stats.lines + usize::from(!newline);
+        if self
+            .stats
+            .longest_line
+            .is_none_or(|(_, longest)| self.line > longest)
+            && (newline || self.line > 0)
+        {
+            self.stats.longest_line = Some((number, self.line));
+        }
+        self.line = 0;
+    }
+}
+
+impl Stats {
+    /// The counts of `text`, all at once.
+    pub fn of(text: &str) -> Stats {
+        let mut counter = Counter::new();
+        counter.feed(text.as_bytes());
+        counter.finish()
+    }
+
+    /// The `n` most frequent words, lowercase, most frequent first, and in alphabetical order
+    /// when as frequent.
+    pub fn top(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut top: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .map(|(word, &count)| (word.as_str(), count))
+            .collect();
+        top.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        top.truncate(n);
+        top
+    }
+
+    /// The number of different words.
+    pub fn distinct_words(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Adds the counts of `other`, a text that comes after this one, like `cat` would. Its words
+    /// are moved into these counts, not copied.
+    pub fn add(&mut self, other: Stats) {
+        if let Some((line, chars)) = other.longest_line
+            && self.longest_line.is_none_or(|(_, longest)| chars > longest)
+        {
+            self.longest_line = Some((self.lines + line, chars));
+        }
+        self.lines += other.lines;
+        self.words += other.words;
+        self.chars += other.chars;
+        self.bytes += other.bytes;
+        for (word, count) in other.counts {
+            *self.counts.entry(word).or_default() += count;
+        }
+    }
+}
+
+// The offset of the first whitespace `char` of `bytes`, which can start with the end of a `char`.
+fn first_whitespace(bytes: &[u8]) -> Option<usize> {
+    (0..bytes.len()).find(|&at| whitespace_at(bytes, at).is_some())
+}
+
+// The offset just after the last whitespace `char` of `bytes`.
+fn last_whitespace_end(bytes: &[u8]) -> Option<usize> {
+    (0..bytes.len())
+        .rev()
+        .find_map(|at| whitespace_at(bytes, at).map(|len| at + len))
+}
+
+// The length of the whitespace `char` that starts at `at`, if one does.
+fn whitespace_at(bytes: &[u8], at: usize) -> Option<usize> {
+    let len = match bytes[at] {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => return None,
+    };
+    let c = std::str::from_utf8(bytes.get(at..at + len)?)
+        .ok()?
+        .chars()
+        .next()?;
+    c.is_whitespace().then_some(len)
+}
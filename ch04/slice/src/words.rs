@@ -0,0 +1,155 @@
+//! The words of a `&str`, as slices of it.
+//!
+//! The book's [`first_word`](crate::first_word) looks for the byte `b' '`, so it reads `"hello\tworld"` as one word,
+//! `"non\u{a0}breaking"` too, and the first word of `"  hello"` as `""`. [`Words`] splits at
+//! every `char` with the Unicode White_Space property, which is what `char::is_whitespace`
+//! checks, and can split at punctuation too. Each word is a slice of the text, with the bytes
+//! of the text it is at: nothing is copied, so the words borrow the text like `first_word`'s
+//! slice does.
+//!
+//! ```
+//! use slice::words::{first_word, nth_word, words};
+//!
+//! assert_eq!(first_word("\t Hello,\u{a0}world!"), Some("Hello,"));
+//! assert_eq!(nth_word("one\ntwo three", 1), Some("two"));
+//! let spans: Vec<_> = words("Hello, world!").split_punctuation().map(|word| word.span).collect();
+//! assert_eq!(spans, [0..5, 7..12]);
+//! ```
+
+use std::ops::Range;
+
+/// A word, and the bytes of the text it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Word<'a> {
+    pub text: &'a str,
+    pub span: Range<usize>,
+}
+
+/// The words of a `&str`, see [`words`].
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    text: &'a str,
+    // the words not yielded yet are in `text[front..back]`
+    front: usize,
+    back: usize,
+    punctuation: bool,
+}
+
+/// The words of `text`, split at whitespace, from both ends.
+pub fn words(text: &str) -> Words<'_> {
+    Words {
+        text,
+        front: 0,
+        back: text.len(),
+        punctuation: false,
+    }
+}
+
+/// The first word of `text`, split at whitespace.
+pub fn first_word(text: &str) -> Option<&str> {
+    words(text).next().map(|word| word.text)
+}
+
+/// The last word of `text`, split at whitespace.
+pub fn last_word(text: &str) -> Option<&str> {
+    words(text).next_back().map(|word| word.text)
+}
+
+/// Word `n` of `text`, counted from 0, split at whitespace.
+pub fn nth_word(text: &str, n: usize) -> Option<&str> {
+    words(text).nth(n).map(|word| word.text)
+}
+
+impl<'a> Words<'a> {
+    /// Splits at punctuation too, which isn't part of any word: `"Hello, world!"` is `Hello`
+    /// and `world`. An apostrophe or a hyphen between two letters or digits joins them, so
+    /// `don't` and `well-known` are one word.
+    pub fn split_punctuation(mut self) -> Self {
+        self.punctuation = true;
+        self
+    }
+
+    // Whether the `c` at `at` of the text is between words.
+    fn splits(&self, at: usize, c: char) -> bool {
+        if c.is_whitespace() {
+            return true;
+        }
+        if !self.punctuation || !is_punctuation(c) {
+            return false;
+        }
+        let before = self.text[..at].chars().next_back();
+        let after = self.text[at + c.len_utf8()..].chars().next();
+        let joins = matches!(c, '\'' | '’' | '-' | '‐')
+            && before.is_some_and(char::is_alphanumeric)
+            && after.is_some_and(char::is_alphanumeric);
+        !joins
+    }
+
+    fn word(&self, span: Range<usize>) -> Word<'a> {
+        Word {
+            text: &self.text[span.clone()],
+            span,
+        }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = Word<'a>;
+
+    fn next(&mut self) -> Option<Word<'a>> {
+        let rest = &self.text[self.front..self.back];
+        let Some((start, _)) = rest
+            .char_indices()
+            .find(|&(at, c)| !self.splits(self.front + at, c))
+        else {
+            self.front = self.back;
+            return None;
+        };
+        let start = self.front + start;
+        let end = self.text[start..self.back]
+            .char_indices()
+            .find(|&(at, c)| self.splits(start + at, c))
+            .map_or(self.back, |(at, _)| start + at);
+        self.front = end;
+        Some(self.word(start..end))
+    }
+}
+
+impl DoubleEndedIterator for Words<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.front..self.back];
+        let Some((last, c)) = rest
+            .char_indices()
+            .rev()
+            .find(|&(at, c)| !self.splits(self.front + at, c))
+        else {
+            self.back = self.front;
+            return None;
+        };
+        let end = self.front + last + c.len_utf8();
+        let start = self.text[self.front..end]
+            .char_indices()
+            .rev()
+            .find(|&(at, c)| self.splits(self.front + at, c))
+            .map_or(self.front, |(at, c)| self.front + at + c.len_utf8());
+        self.back = start;
+        Some(self.word(start..end))
+    }
+}
+
+impl std::iter::FusedIterator for Words<'_> {}
+
+// ASCII punctuation, and the punctuation of Latin-1, General Punctuation and CJK text. Std has
+// no Unicode punctuation property to ask.
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(c,
+            '¡' | '«' | '·' | '»' | '¿'
+            | '\u{2010}'..='\u{2027}'
+            | '\u{2030}'..='\u{205E}'
+            | '\u{3001}'..='\u{3003}'
+            | '\u{3008}'..='\u{3011}'
+            | '\u{FF01}'..='\u{FF0F}'
+            | '\u{FF1A}'..='\u{FF20}'
+        )
+}
@@ -0,0 +1,159 @@
+// CSV records of RFC 4180, which fields borrow the text, where errors are, and the writer.
+
+use std::borrow::Cow;
+
+use slice::csv::{Error, ErrorKind, Writer, quote, reader};
+
+fn records(text: &str) -> Vec<Vec<String>> {
+    reader(text)
+        .map(|record| record.unwrap().iter().map(String::from).collect())
+        .collect()
+}
+
+#[test]
+fn records_of_rfc_4180() {
+    // (text, its records)
+    let cases: &[(&str, &[&[&str]])] = &[
+        ("a,b\r\nc,d\r\n", &[&["a", "b"], &["c", "d"]]),
+        ("a,b\nc,d", &[&["a", "b"], &["c", "d"]]),
+        ("a,,\n,,c\n", &[&["a", "", ""], &["", "", "c"]]),
+        ("\"a,b\",\"\"\n", &[&["a,b", ""]]),
+        ("\"say \"\"hi\"\"\",x\n", &[&["say \"hi\"", "x"]]),
+        (
+            "\"two\r\nlines\",\"and\nmore\"\r\n",
+            &[&["two\r\nlines", "and\nmore"]],
+        ),
+        (" spaces stay , x \n", &[&[" spaces stay ", " x "]]),
+        ("\n\r\na\n\nb\n\n", &[&["a"], &["b"]]),
+        ("ünï,日本\n", &[&["ünï", "日本"]]),
+        ("lone\rcr,x\n", &[&["lone\rcr", "x"]]),
+        ("", &[]),
+    ];
+    for (text, expected) in cases {
+        assert_eq!(records(text), *expected, "{text:?}");
+    }
+}
+
+#[test]
+fn fields_without_doubled_quotes_are_slices_of_the_text() {
+    let text = String::from("plain,\"quoted, with comma\",\"a \"\"quote\"\"\"\n");
+    let record = reader(&text).next().unwrap().unwrap();
+    let range = text.as_bytes().as_ptr_range();
+    for field in &record.fields[..2] {
+        let Cow::Borrowed(slice) = field else {
+            panic!("{field:?} was copied");
+        };
+        assert!(
+            range.contains(&slice.as_ptr()),
+            "{slice:?} isn't in the text"
+        );
+    }
+    assert!(matches!(&record.fields[2], Cow::Owned(field) if field == "a \"quote\""));
+}
+
+#[test]
+fn records_know_their_line() {
+    let text = "h1,h2\n\"multi\nline\",x\n\nlast,y\n";
+    let lines: Vec<usize> = reader(text).map(|record| record.unwrap().line).collect();
+    assert_eq!(lines, [1, 2, 5]);
+}
+
+#[test]
+fn headers_name_the_columns() {
+    let mut csv = reader("id,name\n1,Ada\n2,Grace\n").with_headers().unwrap();
+    assert_eq!(csv.headers().unwrap().fields, ["id", "name"]);
+    assert_eq!((csv.column("name"), csv.column("age")), (Some(1), None));
+    let name = csv.column("name").unwrap();
+    let names: Vec<String> = csv
+        .by_ref()
+        .map(|record| record.unwrap().get(name).unwrap().to_string())
+        .collect();
+    assert_eq!(names, ["Ada", "Grace"]);
+    assert!(reader("").with_headers().unwrap().headers().is_none());
+    assert!(reader("a,\"b\n").with_headers().is_err());
+}
+
+#[test]
+fn errors_say_where_the_text_is_malformed() {
+    let at = |line, column, kind| Error { line, column, kind };
+    // (text, the error)
+    let cases = [
+        ("ab\"c\n", at(1, 3, ErrorKind::QuoteInField)),
+        ("a,b\nc,\"d", at(2, 3, ErrorKind::UnterminatedQuote)),
+        ("\"a\"b,c\n", at(1, 4, ErrorKind::TextAfterQuote)),
+        ("\"a\" ,c\n", at(1, 4, ErrorKind::TextAfterQuote)),
+        (
+            "x,y\n\"two\nlines\"!,y\n",
+            at(3, 7, ErrorKind::TextAfterQuote),
+        ),
+        ("日本,\"語\"x\n", at(1, 7, ErrorKind::TextAfterQuote)),
+        (
+            "a,b\n\n1,2,3\n",
+            at(
+                3,
+                1,
+                ErrorKind::FieldCount {
+                    expected: 2,
+                    found: 3,
+                },
+            ),
+        ),
+        ("a\n\"x,y\"z", at(2, 6, ErrorKind::TextAfterQuote)),
+    ];
+    for (text, expected) in cases {
+        let error = reader(text).find_map(Result::err);
+        assert_eq!(error, Some(expected), "{text:?}");
+    }
+    assert_eq!(
+        at(
+            2,
+            3,
+            ErrorKind::FieldCount {
+                expected: 2,
+                found: 1
+            }
+        )
+        .to_string(),
+        "line 2, column 3: expected 2 fields, like the first record, found 1"
+    );
+}
+
+#[test]
+fn the_first_error_ends_the_records() {
+    let results: Vec<_> = reader("a\nb\"\nc\n").collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok() && results[1].is_err());
+}
+
+#[test]
+fn quote_only_copies_the_fields_that_need_it() {
+    assert!(matches!(quote("plain text"), Cow::Borrowed("plain text")));
+    assert_eq!(quote("a,b"), "\"a,b\"");
+    assert_eq!(quote("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(quote("two\nlines"), "\"two\nlines\"");
+}
+
+#[test]
+fn written_records_read_back_the_same() {
+    let rows: &[&[&str]] = &[
+        &["id", "text", "empty"],
+        &["1", "comma, and \"quotes\"", ""],
+        &["2", "line\r\nbreak", "\r"],
+        &["3", "", "ünï"],
+    ];
+    let mut writer = Writer::new(String::new());
+    for row in rows {
+        writer.write_record(*row).unwrap();
+    }
+    let text = writer.into_inner();
+    assert!(text.starts_with("id,text,empty\r\n1,\"comma, and \"\"quotes\"\"\",\r\n"));
+    assert_eq!(records(&text), rows);
+
+    // a lone empty field isn't written as a blank line, which would be skipped
+    let mut writer = Writer::new(String::new());
+    writer.write_record([""]).unwrap();
+    writer.write_record(["x"]).unwrap();
+    let text = writer.into_inner();
+    assert_eq!(text, "\"\"\r\nx\r\n");
+    assert_eq!(records(&text), [[""], ["x"]]);
+}
@@ -0,0 +1,89 @@
+// Every finder of `slice::find` against the book's loop: at every length, offset and position.
+
+use slice::find::{find_byte, first_word_bytes, naive, swar};
+
+type Finder = fn(&[u8], u8) -> Option<usize>;
+
+const FINDERS: &[(&str, Finder)] = &[
+    ("find_byte", find_byte),
+    ("swar", swar),
+    #[cfg(all(feature = "sse2", target_arch = "x86_64"))]
+    ("sse2", slice::find::sse2),
+];
+
+// Needles next to the values where a bit trick could go wrong: 0, the high bit, 0xff.
+const NEEDLES: [u8; 8] = [0x00, 0x01, b' ', 0x7f, 0x80, 0x81, 0xfe, 0xff];
+
+#[test]
+fn finders_agree_with_the_book_loop_everywhere() {
+    for needle in NEEDLES {
+        // the bytes around the needle, which differ from it by one bit or borrow
+        let others = [
+            needle ^ 1,
+            needle.wrapping_add(1),
+            needle ^ 0x80,
+            needle.wrapping_sub(1),
+        ];
+        for len in 0..70 {
+            let mut haystack: Vec<u8> = (0..len).map(|i| others[i % others.len()]).collect();
+            for (name, finder) in FINDERS {
+                assert_eq!(
+                    finder(&haystack, needle),
+                    None,
+                    "{name}, {len} bytes, {needle:#x}"
+                );
+            }
+            for at in 0..len {
+                // and a second one later, which the first hides
+                let later = (at + 9).min(len - 1);
+                haystack[at] = needle;
+                haystack[later] = needle;
+                for (name, finder) in FINDERS {
+                    assert_eq!(
+                        finder(&haystack, needle),
+                        Some(at),
+                        "{name}, {needle:#x} at {at}"
+                    );
+                }
+                haystack[at] = others[at % others.len()];
+                haystack[later] = others[later % others.len()];
+            }
+        }
+    }
+}
+
+#[test]
+fn finders_dont_need_aligned_slices() {
+    let bytes: Vec<u8> = (0..=255).cycle().take(600).collect();
+    for offset in 0..16 {
+        let haystack = &bytes[offset..];
+        for needle in NEEDLES {
+            for (name, finder) in FINDERS {
+                assert_eq!(
+                    finder(haystack, needle),
+                    naive(haystack, needle),
+                    "{name}, offset {offset}, {needle:#x}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn first_word_bytes_stops_at_the_first_space() {
+    // (text, its first word)
+    let cases = [
+        ("Hello world", "Hello"),
+        (
+            "no-space-at-all-in-a-text-longer-than-16-bytes",
+            "no-space-at-all-in-a-text-longer-than-16-bytes",
+        ),
+        (" leading", ""),
+        ("ünïcödé text", "ünïcödé"),
+        ("tab\tisn't a space", "tab\tisn't"),
+        ("", ""),
+    ];
+    for (text, first) in cases {
+        assert_eq!(first_word_bytes(text), first, "{text:?}");
+    }
+}
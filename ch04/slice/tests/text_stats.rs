@@ -0,0 +1,229 @@
+// `Counter` fed in chunks of every size, and the `textstats` binary on files and stdin.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use slice::text_stats::{Counter, MAX_WORD, Stats};
+
+const TEXT: &str = "Ünïcödé words, straddling\u{a0}chunks:\n\
+                    the café's well-known crème brûlée\n\
+                    \n\
+                    日本語 テキスト\tand THE end";
+
+fn fed(bytes: &[u8], chunk: usize) -> Stats {
+    let mut counter = Counter::new();
+    for part in bytes.chunks(chunk) {
+        counter.feed(part);
+    }
+    counter.finish()
+}
+
+#[test]
+fn every_chunk_size_counts_the_same() {
+    let whole = Stats::of(TEXT);
+    assert_eq!(
+        (whole.lines, whole.words, whole.chars, whole.bytes),
+        (3, 14, TEXT.chars().count(), TEXT.len())
+    );
+    for chunk in 1..=TEXT.len() {
+        assert_eq!(
+            fed(TEXT.as_bytes(), chunk),
+            whole,
+            "chunks of {chunk} bytes"
+        );
+    }
+}
+
+#[test]
+fn words_are_counted_lowercase_and_split_at_punctuation() {
+    let stats = Stats::of(TEXT);
+    assert_eq!(stats.top(2), [("the", 2), ("and", 1)]);
+    let words: Vec<&str> = stats
+        .top(usize::MAX)
+        .into_iter()
+        .map(|(word, _)| word)
+        .collect();
+    for word in [
+        "ünïcödé",
+        "café's",
+        "well-known",
+        "日本語",
+        "テキスト",
+        "straddling",
+    ] {
+        assert!(words.contains(&word), "{word} in {words:?}");
+    }
+    assert_eq!(stats.distinct_words(), 13);
+}
+
+#[test]
+fn longest_line_is_the_first_of_the_longest() {
+    assert_eq!(Stats::of("ab\nabcd\nabcd\n").longest_line, Some((2, 4)));
+    assert_eq!(
+        Stats::of("ab\nno newline at the end").longest_line,
+        Some((2, 21))
+    );
+    assert_eq!(Stats::of("").longest_line, None);
+}
+
+#[test]
+fn bytes_that_arent_utf8_count_as_replacement_chars() {
+    let bytes = b"caf\xe9 \xff\xfe ok\n";
+    let lossy = String::from_utf8_lossy(bytes);
+    for chunk in 1..=bytes.len() {
+        let stats = fed(bytes, chunk);
+        assert_eq!(
+            stats.chars,
+            lossy.chars().count(),
+            "chunks of {chunk} bytes"
+        );
+        assert_eq!((stats.words, stats.bytes), (3, bytes.len()));
+    }
+    // a `char` cut short by the end of the text
+    assert_eq!(fed("é".as_bytes().split_last().unwrap().1, 1).chars, 1);
+}
+
+#[test]
+fn words_longer_than_max_word_are_counted_but_not_kept() {
+    // no whitespace for 3 times `MAX_WORD`, with `char`s of every length and a byte that isn't
+    // UTF-8, and the same run again at the end of the text
+    let run: Vec<u8> = b"a-\xff\xc3\xa9\xe6\x97\xa5\xf0\x9f\xa6\x80,"
+        .iter()
+        .copied()
+        .cycle()
+        .take(3 * MAX_WORD)
+        .collect();
+    let mut bytes = b"short ".to_vec();
+    bytes.extend_from_slice(&run);
+    // an ideographic space, which chunks of a byte or two cut
+    bytes.extend_from_slice("\u{3000}tail word\n".as_bytes());
+    bytes.extend_from_slice(&run);
+    let lossy = String::from_utf8_lossy(&bytes);
+
+    let whole = fed(&bytes, bytes.len());
+    assert_eq!(
+        (whole.lines, whole.words, whole.chars, whole.bytes),
+        (1, 5, lossy.chars().count(), bytes.len())
+    );
+    assert_eq!(
+        whole.top(usize::MAX),
+        [("short", 1), ("tail", 1), ("word", 1)]
+    );
+    for chunk in [
+        1,
+        2,
+        3,
+        5,
+        64,
+        MAX_WORD - 1,
+        MAX_WORD,
+        MAX_WORD + 1,
+        2 * MAX_WORD,
+    ] {
+        let mut counter = Counter::new();
+        for part in bytes.chunks(chunk) {
+            counter.feed(part);
+            assert!(
+                counter.carried() <= MAX_WORD + chunk,
+                "{} bytes carried with chunks of {chunk} bytes",
+                counter.carried()
+            );
+        }
+        assert_eq!(counter.finish(), whole, "chunks of {chunk} bytes");
+    }
+}
+
+#[test]
+fn add_counts_texts_one_after_the_other() {
+    let (first, second) = ("one two\nthree\n", "four\nfive six seven\n");
+    let mut stats = Stats::of(first);
+    stats.add(Stats::of(second));
+    assert_eq!(stats, Stats::of(&format!("{first}{second}")));
+    assert_eq!(stats.longest_line, Some((4, 14)));
+}
+
+fn textstats(args: &[&str], stdin: &str) -> (String, i32) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_textstats"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        output.status.code().unwrap(),
+    )
+}
+
+#[test]
+fn the_binary_prints_a_table_of_stdin() {
+    let (out, code) = textstats(&["--top", "2", "--chunk", "3"], "the cat\nsaw the dog\n");
+    assert_eq!(code, 0);
+    assert_eq!(
+        out,
+        "   lines    words    chars    bytes  file\n\
+         \x20      2        5       20       20  -\n\
+         longest line: -:2, 11 chars\n\
+         most frequent of 4 words:\n\
+         \x20      2  the\n\
+         \x20      1  cat\n"
+    );
+}
+
+#[test]
+fn the_binary_prints_json_of_files_and_keeps_going_after_errors() {
+    let dir = std::env::temp_dir().join(format!("textstats-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("a \"quoted\".txt");
+    std::fs::write(&file, "Hi hi\n").unwrap();
+    let missing = dir.join("missing.txt");
+    let (out, code) = textstats(
+        &[
+            "--json",
+            "--top",
+            "1",
+            file.to_str().unwrap(),
+            missing.to_str().unwrap(),
+            "-",
+        ],
+        "bye\n",
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(code, 1);
+    let name = file.to_str().unwrap().replace('"', "\\\"");
+    assert_eq!(
+        out,
+        format!(
+            "{{\"files\": [\n  \
+             {{\"name\": \"{name}\", \"lines\": 1, \"words\": 2, \"chars\": 6, \"bytes\": 6, \
+             \"longest_line\": {{\"line\": 1, \"chars\": 5}}, \
+             \"top_words\": [{{\"word\": \"hi\", \"count\": 2}}]}},\n  \
+             {{\"name\": \"-\", \"lines\": 1, \"words\": 1, \"chars\": 4, \"bytes\": 4, \
+             \"longest_line\": {{\"line\": 1, \"chars\": 3}}, \
+             \"top_words\": [{{\"word\": \"bye\", \"count\": 1}}]}}\n\
+             ], \"total\": {{\"lines\": 2, \"words\": 3, \"chars\": 10, \"bytes\": 10, \
+             \"longest_line\": {{\"file\": \"{name}\", \"line\": 1, \"chars\": 5}}, \
+             \"distinct_words\": 2, \"top_words\": [{{\"word\": \"hi\", \"count\": 2}}]}}}}\n"
+        )
+    );
+}
+
+#[test]
+fn the_binary_rejects_bad_options() {
+    for args in [
+        &["--top"][..],
+        &["--chunk", "0"],
+        &["--top", "x"],
+        &["--verbose"],
+    ] {
+        assert_eq!(textstats(args, "").1, 2, "{args:?}");
+    }
+}
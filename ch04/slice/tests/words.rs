@@ -0,0 +1,98 @@
+// `Words` on the whitespace `b' '` misses, on punctuation, and from both ends.
+
+use slice::words::{Word, first_word, last_word, nth_word, words};
+
+#[test]
+fn whitespace_of_every_kind_splits() {
+    // (text, its words)
+    let cases: &[(&str, &[&str])] = &[
+        ("Hello, world!", &["Hello,", "world!"]),
+        ("  leading and trailing  ", &["leading", "and", "trailing"]),
+        (
+            "tab\tnewline\ncarriage\r\nreturn",
+            &["tab", "newline", "carriage", "return"],
+        ),
+        (
+            "non\u{a0}breaking\u{202f}narrow",
+            &["non", "breaking", "narrow"],
+        ),
+        (
+            "ideographic\u{3000}space\u{2003}em",
+            &["ideographic", "space", "em"],
+        ),
+        ("Ιωάννης και 日本語", &["Ιωάννης", "και", "日本語"]),
+        ("", &[]),
+        (" \t\n ", &[]),
+    ];
+    for &(text, expected) in cases {
+        let forward: Vec<&str> = words(text).map(|word| word.text).collect();
+        let mut backward: Vec<&str> = words(text).rev().map(|word| word.text).collect();
+        backward.reverse();
+        assert_eq!(forward, expected, "{text:?}");
+        assert_eq!(backward, expected, "{text:?} from the back");
+    }
+}
+
+#[test]
+fn words_are_slices_of_the_text_at_their_spans() {
+    let text = String::from("  héllo\tworld ");
+    for Word { text: word, span } in words(&text) {
+        assert_eq!(&text[span.clone()], word);
+        // the same bytes, not a copy
+        assert_eq!(word.as_ptr(), text[span].as_ptr());
+    }
+    let spans: Vec<_> = words(&text).map(|word| word.span).collect();
+    assert_eq!(spans, [2..8, 9..14]);
+}
+
+#[test]
+fn punctuation_splits_when_asked() {
+    let cases: &[(&str, &[&str])] = &[
+        ("Hello, world!", &["Hello", "world"]),
+        (
+            "don't stop—it's well-known",
+            &["don't", "stop", "it's", "well-known"],
+        ),
+        (
+            "'quoted' (and) «guillemets» ...",
+            &["quoted", "and", "guillemets"],
+        ),
+        ("日本語、テスト。", &["日本語", "テスト"]),
+        ("a-b -c d- x’y", &["a-b", "c", "d", "x’y"]),
+    ];
+    for &(text, expected) in cases {
+        let forward: Vec<&str> = words(text)
+            .split_punctuation()
+            .map(|word| word.text)
+            .collect();
+        let mut backward: Vec<&str> = words(text)
+            .split_punctuation()
+            .rev()
+            .map(|word| word.text)
+            .collect();
+        backward.reverse();
+        assert_eq!(forward, expected, "{text:?}");
+        assert_eq!(backward, expected, "{text:?} from the back");
+    }
+}
+
+#[test]
+fn first_last_and_nth() {
+    let text = "\n  one\ttwo\u{a0}three  ";
+    assert_eq!(first_word(text), Some("one"));
+    assert_eq!(last_word(text), Some("three"));
+    assert_eq!(nth_word(text, 1), Some("two"));
+    assert_eq!(nth_word(text, 3), None);
+    assert_eq!(first_word("   "), None);
+    assert_eq!(last_word(""), None);
+}
+
+#[test]
+fn both_ends_meet_in_the_middle() {
+    let mut all = words("one two three four");
+    assert_eq!(all.next().map(|word| word.text), Some("one"));
+    assert_eq!(all.next_back().map(|word| word.text), Some("four"));
+    assert_eq!(all.next_back().map(|word| word.text), Some("three"));
+    assert_eq!(all.next().map(|word| word.text), Some("two"));
+    assert_eq!((all.next(), all.next_back()), (None, None));
+}
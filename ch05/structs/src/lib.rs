@@ -0,0 +1,67 @@
+use common::{Chapter, Demo, Reporter, say};
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch05/structs",
+    package: "structs",
+    description: "Structs, tuple structs and unit structs",
+    banner: Some("Hello, world!"),
+    demos: &[
+        Demo {
+            name: "update_email_demo",
+            description: "Struct update syntax moves the remaining fields",
+            run: |out| {
+                update_email_demo(out);
+            },
+        },
+        Demo {
+            name: "tuple_structs_demo",
+            description: "Tuple structs and unit-like structs",
+            run: |out| {
+                tuple_structs_demo(out);
+            },
+        },
+    ],
+};
+
+/// Creates a user, updates its email and returns the updated user.
+pub fn update_email_demo(out: &mut dyn Reporter) -> User {
+    let user_1 = User{username : String::from("ioannispli"), email : String::from("johnplitharas@domain.com"), active : true };
+    let user_1 = update_email(String::from("johnplitharas@other_domain.com"), user_1);
+    say!(out, "user email updated to: {}", user_1.email); // because String doesn't implement the copy, same as our struct we aren't able to use again the user_1
+    // because it changed ownership, moved to function and back
+    user_1
+}
+
+/// Creates a tuple struct and a unit struct, returns the tuple struct.
+pub fn tuple_structs_demo(out: &mut dyn Reporter) -> Color {
+    let red = Color(0,0,0);
+    say!(out, "red is: `{:?}`", red);
+    let _my_boolean = AlwaysEqual ;
+    red
+}
+
+/// An RGB color.
+#[derive(Debug)]
+pub struct Color(pub i32, pub i32, pub i32); // tuple struct
+
+
+/// A struct without any fields.
+pub struct AlwaysEqual;  // unit struct
+
+/// A user account.
+#[derive(Debug)]
+pub struct User{
+    pub username : String,
+    pub email : String,
+    pub active: bool
+
+
+
+}
+
+
+/// Returns a new user with `new_email`, every other field is moved out of `user`.
+pub fn update_email(new_email : String, user : User ) -> User{
+    User{email: new_email, ..user}
+
+}
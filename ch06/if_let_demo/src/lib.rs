@@ -0,0 +1,158 @@
+/*
+===============================================================
+        Concise Control Flow with if let and let else
+===============================================================
+The if let syntax lets you combine if and let into a less verbose way to handle values that match one pattern while ignoring the rest.
+
+
+==================== let .. else ==============================
+ Rust has let...else. The let...else syntax takes a pattern on the left side and an expression on the right, very similar to if let, 
+ but it does not have an if branch, only an else branch. If the pattern matches, it will bind the value from the pattern in the outer scope. 
+ If the pattern does not match, the program will flow into the else arm, which must return from the function.
+==============================================================
+*/
+
+/// A coin, only quarters carry a state.
+pub enum Coin{
+    Quarter(UsState),
+    Peni,
+}
+/// The state printed on a quarter.
+#[derive(Debug)]
+pub enum UsState{
+    Alabama,
+    Alaska,
+}
+
+impl UsState {
+    /// `true` when the state already existed in `year`.
+    pub fn existed_in(&self, year: u16) -> bool {
+        match self {
+            UsState::Alabama => year >= 1819,
+            UsState::Alaska => year >= 1959,
+            // -- snip --
+        }
+    }
+}
+
+use common::{Chapter, Demo, Reporter, say};
+
+pub const CHAPTER: Chapter = Chapter {
+    path: "ch06/if_let_demo",
+    package: "if_let_demo",
+    description: "Concise control flow with `if let` and `let..else`",
+    banner: None,
+    demos: &[
+        Demo {
+            name: "if_let",
+            description: "`match` compared with `if let`",
+            run: |out| {
+                if_let(out);
+            },
+        },
+        Demo {
+            name: "let_else",
+            description: "Return early with `let..else`",
+            run: |out| {
+                let_else(out);
+            },
+        },
+        Demo {
+            name: "describe_quarters",
+            description: "`let..else` compared with `if let` for the same function",
+            run: |out| {
+                describe_quarters(out);
+            },
+        },
+    ],
+};
+
+// the `match` is kept on purpose, to compare it with the `if let` right after it
+#[allow(clippy::single_match)]
+/// Reads an optional maximum with `match` and with `if let`, returns it.
+pub fn if_let(out: &mut dyn Reporter) -> Option<u8> {
+    // instead of doing this we can
+    let config_max = Some(3u8);
+    match config_max {
+        Some(max) => say!(out, "The maximum is configured to be {}", max),
+        _ => (),
+    };
+
+    if let Some(max) = config_max {
+        say!(out, "The maximum is configured to be: `{}`", max);
+    }
+    let name: Option<String> = None ;
+    if let Some(name) = name {
+        say!(out, "Name is: {}", name);
+    } else {
+        say!(out, "Name is None: {:?}", name);
+    }
+    config_max
+}
+
+/// Greets nobody and then someone, returns the two greetings.
+pub fn let_else(out: &mut dyn Reporter) -> [Option<String>; 2] {
+    [greet_someone(out, None), greet_someone(out, Some(String::from("Ioannis")))]
+}
+
+/// Describes a few coins with both versions of the function, returns the descriptions.
+pub fn describe_quarters(out: &mut dyn Reporter) -> Vec<Option<String>> {
+    let descriptions = vec![
+        describe_state_quarter(Coin::Quarter(UsState::Alabama)),
+        describe_state_quarter(Coin::Quarter(UsState::Alaska)),
+        describe_state_quarter(Coin::Peni),
+        describe_state_quarter_odd(Coin::Quarter(UsState::Alaska)),
+        describe_state_quarter_odd(Coin::Peni),
+    ];
+    for description in &descriptions {
+        say!(out, "{:?}", description);
+    }
+    descriptions
+}
+
+// use let..else
+/// How old the state of a quarter is, `None` for any other coin.
+pub fn describe_state_quarter(coin: Coin) -> Option<String> {
+    let Coin::Quarter(state) = coin else {
+        return None;
+    };
+
+    if state.existed_in(1900) {
+        Some(format!("{state:?} is pretty old, for America!"))
+    } else {
+        Some(format!("{state:?} is relatively new."))
+    }
+}
+
+// checking first with if..let
+/// Same as [`describe_state_quarter`], written with `if let` instead of `let..else`.
+pub fn describe_state_quarter_odd(coin: Coin) -> Option<String> {
+    let state = if let Coin::Quarter(state) = coin {
+        state
+    } else {
+        return None;
+    };
+
+    if state.existed_in(1900) {
+        Some(format!("{state:?} is pretty old, for America!"))
+    } else {
+        Some(format!("{state:?} is relatively new."))
+    }
+}
+
+
+
+
+/// Greets `name` and returns the greeting, returns early with `None` when there is no name.
+pub fn greet_someone(out: &mut dyn Reporter, name: Option<String>) -> Option<String> {
+    let Some(name) = name else {
+        say!(out, "No name provided.");
+        return None;
+    };
+
+    let greeting = format!("Hello, {}!", name);
+    say!(out, "{}", greeting);
+    Some(greeting)
+}
+
+
@@ -0,0 +1,80 @@
+//! Pieces shared by every chapter crate: the description of a chapter and its demos,
+//! where the demos write their output, and the `main` each chapter binary runs.
+
+pub mod report;
+
+use std::process::ExitCode;
+
+pub use report::{JsonReporter, NullReporter, Reporter, TextReporter, push_json_string};
+
+/// A chapter crate of the workspace, e.g. `ch05/rectangles`.
+pub struct Chapter {
+    /// Directory of the crate relative to the workspace root.
+    pub path: &'static str,
+    /// Name of the cargo package.
+    pub package: &'static str,
+    /// One line describing what the chapter is about.
+    pub description: &'static str,
+    /// Reported before the demos when the whole chapter runs.
+    pub banner: Option<&'static str>,
+    /// The demos, in the order they run.
+    pub demos: &'static [Demo],
+}
+
+/// A demo function that can run on its own.
+pub struct Demo {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub run: fn(&mut dyn Reporter),
+}
+
+impl Chapter {
+    /// Reports the banner and runs every demo.
+    pub fn run_all(&self, out: &mut dyn Reporter) {
+        if let Some(banner) = self.banner {
+            out.begin(self.package);
+            say!(out, "{}", banner);
+        }
+        for demo in self.demos {
+            demo.run_with(out);
+        }
+    }
+
+    /// Looks up a demo by its name.
+    pub fn demo(&self, name: &str) -> Option<&'static Demo> {
+        self.demos.iter().find(|demo| demo.name == name)
+    }
+}
+
+impl Demo {
+    /// Tells the reporter which demo runs and runs it.
+    pub fn run_with(&self, out: &mut dyn Reporter) {
+        out.begin(self.name);
+        (self.run)(out);
+    }
+}
+
+/// The `main` of a chapter binary: runs the demo named by the first argument, or all of them,
+/// and prints their output to stdout.
+///
+/// `cargo run -p control_flow -- loop_with_name` runs a single demo.
+pub fn main(chapter: &Chapter) -> ExitCode {
+    let mut out = TextReporter::stdout();
+    match std::env::args().nth(1) {
+        None => chapter.run_all(&mut out),
+        Some(name) => match chapter.demo(&name) {
+            Some(demo) => demo.run_with(&mut out),
+            None => {
+                eprintln!("`{}` has no demo named `{name}`", chapter.path);
+                return ExitCode::from(2);
+            }
+        },
+    }
+    match out.finish() {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("failed to write the output: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Where the demos write their output.
+//!
+//! A demo never calls `println!`, it reports each step through [`say!`](crate::say) to a
+//! [`Reporter`] given by the caller. [`TextReporter`] prints the steps as lines, the way the
+//! chapters always did, and [`JsonReporter`] emits one JSON object per step.
+
+use std::fmt::{self, Debug, Write as _};
+use std::io::{self, Write};
+
+/// Receives the output of the demos.
+pub trait Reporter {
+    /// A demo is about to run, every following step belongs to it.
+    fn begin(&mut self, demo: &str);
+
+    /// One step of the running demo: the message as it reads on the screen and the
+    /// values used in it, named after the expression that produced them.
+    fn step(&mut self, message: fmt::Arguments<'_>, values: &[(&str, &dyn Debug)]);
+}
+
+/// Writes every step as a line of text, like `println!` does.
+pub struct TextReporter<W> {
+    out: W,
+    error: Option<io::Error>,
+}
+
+impl TextReporter<io::Stdout> {
+    /// The default reporter, prints to stdout.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> TextReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, error: None }
+    }
+
+    /// Gives back the writer, or the first error that happened while writing to it.
+    pub fn finish(self) -> io::Result<W> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.out),
+        }
+    }
+}
+
+impl<W: Write> Reporter for TextReporter<W> {
+    fn begin(&mut self, _demo: &str) {}
+
+    fn step(&mut self, message: fmt::Arguments<'_>, _values: &[(&str, &dyn Debug)]) {
+        if self.error.is_none() {
+            self.error = writeln!(self.out, "{message}").err();
+        }
+    }
+}
+
+/// Drops every step, for running a demo only for what it does (or allocates).
+pub struct NullReporter;
+
+impl Reporter for NullReporter {
+    fn begin(&mut self, _demo: &str) {}
+
+    fn step(&mut self, _message: fmt::Arguments<'_>, _values: &[(&str, &dyn Debug)]) {}
+}
+
+/// Writes every step as a JSON object on its own line:
+///
+/// ```text
+/// {"demo":"shadowing","message":"x is: `6`","values":{"x":6}}
+/// ```
+///
+/// Values are written with their `Debug` format, as JSON numbers and booleans when they
+/// look like one and as strings otherwise.
+pub struct JsonReporter<W> {
+    out: W,
+    demo: String,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> JsonReporter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            demo: String::new(),
+            error: None,
+        }
+    }
+
+    /// Gives back the writer, or the first error that happened while writing to it.
+    pub fn finish(self) -> io::Result<W> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.out),
+        }
+    }
+}
+
+impl<W: Write> Reporter for JsonReporter<W> {
+    fn begin(&mut self, demo: &str) {
+        self.demo = demo.to_string();
+    }
+
+    fn step(&mut self, message: fmt::Arguments<'_>, values: &[(&str, &dyn Debug)]) {
+        if self.error.is_some() {
+            return;
+        }
+        let mut line = String::from("{\"demo\":");
+        push_json_string(&mut line, &self.demo);
+        line.push_str(",\"message\":");
+        push_json_string(&mut line, &message.to_string());
+        line.push_str(",\"values\":{");
+        for (i, (name, value)) in values.iter().enumerate() {
+            if i > 0 {
+                line.push(',');
+            }
+            push_json_string(&mut line, name);
+            line.push(':');
+            push_json_value(&mut line, &format!("{value:?}"));
+        }
+        line.push_str("}}");
+        self.error = writeln!(self.out, "{line}").err();
+    }
+}
+
+fn push_json_value(out: &mut String, debug: &str) {
+    let is_number = debug.parse::<f64>().is_ok_and(f64::is_finite)
+        && debug.starts_with(|c: char| c == '-' || c.is_ascii_digit());
+    if is_number || debug == "true" || debug == "false" {
+        out.push_str(debug);
+    } else {
+        push_json_string(out, debug);
+    }
+}
+
+/// Appends `s` to `out` as a JSON string, in quotes and escaped.
+pub fn push_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Reports one step of a demo, takes the same arguments as `println!` after the reporter:
+///
+/// ```ignore
+/// say!(out, "x is: `{}`", x);
+/// ```
+///
+/// Every argument is evaluated once, and is also handed to the reporter as a value named
+/// after its expression, `"x"` above.
+#[macro_export]
+macro_rules! say {
+    ($out:expr, $fmt:literal $(, $value:expr)* $(,)?) => {
+        $crate::__say!(@bind $out, $fmt, [] [] $($value,)*)
+    };
+}
+
+// Binds every argument to its own variable first, so `say!` doesn't evaluate them twice.
+// The `value` of each recursion step is a different variable thanks to macro hygiene.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __say {
+    (@bind $out:expr, $fmt:literal, [$($name:expr),*] [$($var:ident),*] $value:expr, $($rest:expr,)*) => {
+        match &$value {
+            value => $crate::__say!(
+                @bind $out, $fmt, [$($name,)* stringify!($value)] [$($var,)* value] $($rest,)*
+            ),
+        }
+    };
+    (@bind $out:expr, $fmt:literal, [$($name:expr),*] [$($var:ident),*]) => {
+        $crate::report::Reporter::step(
+            &mut *$out,
+            format_args!($fmt $(, $var)*),
+            &[$(($name, $var as &dyn ::std::fmt::Debug)),*],
+        )
+    };
+}
@@ -0,0 +1,9 @@
+#[test]
+fn adds_one() {
+    assert_eq!(plus_one(Some(5)), Some(6));
+}
+
+#[test]
+fn none_stays_none() {
+    assert_eq!(plus_one(None), None);
+}
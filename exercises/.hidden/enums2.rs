@@ -0,0 +1,20 @@
+#[test]
+fn an_old_state() {
+    assert_eq!(
+        describe_state_quarter(Coin::Quarter(UsState::Alabama)).as_deref(),
+        Some("Alabama is pretty old, for America!")
+    );
+}
+
+#[test]
+fn a_new_state() {
+    assert_eq!(
+        describe_state_quarter(Coin::Quarter(UsState::Alaska)).as_deref(),
+        Some("Alaska is relatively new.")
+    );
+}
+
+#[test]
+fn not_a_quarter() {
+    assert_eq!(describe_state_quarter(Coin::Penny), None);
+}
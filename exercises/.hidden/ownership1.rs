@@ -0,0 +1,4 @@
+#[test]
+fn says_the_name_twice() {
+    assert_eq!(greet_twice(String::from("Ferris")), "hello Ferris, goodbye Ferris");
+}
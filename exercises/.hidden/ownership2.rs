@@ -0,0 +1,9 @@
+#[test]
+fn shouts() {
+    assert_eq!(shout(String::from("hello")), "HELLO!");
+}
+
+#[test]
+fn shouts_twice() {
+    assert_eq!(shout_twice(String::from("hey")), "HEY!!");
+}
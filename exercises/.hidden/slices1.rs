@@ -0,0 +1,19 @@
+#[test]
+fn stops_at_a_space() {
+    assert_eq!(first_word("hello world"), "hello");
+}
+
+#[test]
+fn stops_at_a_tab() {
+    assert_eq!(first_word("hello\tworld"), "hello");
+}
+
+#[test]
+fn stops_at_a_newline() {
+    assert_eq!(first_word("one\ntwo"), "one");
+}
+
+#[test]
+fn a_single_word() {
+    assert_eq!(first_word("hello"), "hello");
+}
@@ -0,0 +1,20 @@
+#[test]
+fn two_words() {
+    assert_eq!(last_word("hello world"), "world");
+}
+
+#[test]
+fn many_words() {
+    assert_eq!(last_word("a b c"), "c");
+}
+
+#[test]
+fn a_single_word() {
+    assert_eq!(last_word("hello"), "hello");
+}
+
+#[test]
+fn points_into_the_input() {
+    let s = "hello world";
+    assert_eq!(last_word(s).as_ptr(), s[6..].as_ptr());
+}
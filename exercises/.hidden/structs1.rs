@@ -0,0 +1,23 @@
+#[test]
+fn holds_a_smaller_one() {
+    let big = Rectangle { width: 30, height: 50 };
+    assert!(big.can_hold(&Rectangle { width: 10, height: 40 }));
+}
+
+#[test]
+fn holds_a_turned_one() {
+    let wide = Rectangle { width: 30, height: 10 };
+    assert!(wide.can_hold(&Rectangle { width: 5, height: 20 }));
+}
+
+#[test]
+fn does_not_hold_a_longer_one() {
+    let wide = Rectangle { width: 30, height: 10 };
+    assert!(!wide.can_hold(&Rectangle { width: 5, height: 40 }));
+}
+
+#[test]
+fn does_not_hold_itself() {
+    let square = Rectangle { width: 10, height: 10 };
+    assert!(!square.can_hold(&Rectangle { width: 10, height: 10 }));
+}
@@ -0,0 +1,16 @@
+#[test]
+fn changes_only_the_email() {
+    let user = User {
+        active: true,
+        username: String::from("ferris"),
+        email: String::from("ferris@old.example"),
+        sign_in_count: 3,
+    };
+    let updated = with_email(&user, String::from("ferris@new.example"));
+
+    assert_eq!(updated.email, "ferris@new.example");
+    assert_eq!(updated.username, user.username);
+    assert_eq!(updated.sign_in_count, 3);
+    assert!(updated.active);
+    assert_eq!(user.email, "ferris@old.example");
+}
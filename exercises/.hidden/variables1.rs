@@ -0,0 +1,9 @@
+#[test]
+fn counts_down_to_zero() {
+    assert_eq!(countdown(3), vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn starts_at_zero() {
+    assert_eq!(countdown(0), vec![0]);
+}
@@ -0,0 +1,14 @@
+#[test]
+fn counts_leading_spaces() {
+    assert_eq!(indent_width("    let x = 5;"), 4);
+}
+
+#[test]
+fn no_indent() {
+    assert_eq!(indent_width("fn main() {"), 0);
+}
+
+#[test]
+fn only_spaces() {
+    assert_eq!(indent_width("  "), 2);
+}
@@ -0,0 +1,38 @@
+// enums2: only a quarter has a state to describe, any other coin gives `None`.
+//
+// `let` alone can't take a pattern that might not match. Give it an `else` that leaves the
+// function (see `describe_state_quarter` in ch06/if_let_demo).
+//
+// Run `myrust exercise hint enums2` if you get stuck.
+
+#[derive(Debug)]
+enum UsState {
+    Alabama,
+    Alaska,
+}
+
+impl UsState {
+    fn existed_in(&self, year: u16) -> bool {
+        match self {
+            UsState::Alabama => year >= 1819,
+            UsState::Alaska => year >= 1959,
+        }
+    }
+}
+
+enum Coin {
+    Penny,
+    Quarter(UsState),
+}
+
+fn describe_state_quarter(coin: Coin) -> Option<String> {
+    let Coin::Quarter(state) = coin else {
+        return None;
+    };
+
+    if state.existed_in(1900) {
+        Some(format!("{state:?} is pretty old, for America!"))
+    } else {
+        Some(format!("{state:?} is relatively new."))
+    }
+}
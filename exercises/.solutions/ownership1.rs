@@ -0,0 +1,12 @@
+// ownership1: `greet_twice` should use the name twice.
+//
+// `let first = name.clone();` moves the `String`, so `name` can't be used after it
+// (see `move_demo_1` in ch04/ownership_intro). Keep both lines, and keep the `String`s.
+//
+// Run `myrust exercise hint ownership1` if you get stuck.
+
+fn greet_twice(name: String) -> String {
+    let first = name.clone();
+    let second = name;
+    format!("hello {first}, goodbye {second}")
+}
@@ -0,0 +1,16 @@
+// slices1: `first_word` only knows about spaces.
+//
+// This is `first_word_improved` from ch04/slice. Make it stop at tabs and newlines too.
+//
+// Run `myrust exercise hint slices1` if you get stuck.
+
+fn first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item.is_ascii_whitespace() {
+            return &s[0..i];
+        }
+    }
+    s
+}
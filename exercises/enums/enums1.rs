@@ -0,0 +1,11 @@
+// enums1: `plus_one` doesn't say what happens to `None`.
+//
+// A `match` has to cover every variant (see `plus_one` in ch06/match_demo).
+//
+// Run `myrust exercise hint enums1` if you get stuck.
+
+fn plus_one(x: Option<i32>) -> Option<i32> {
+    match x {
+        Some(i) => Some(i + 1),
+    }
+}
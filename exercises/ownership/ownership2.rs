@@ -0,0 +1,16 @@
+// ownership2: `shout` takes the string, but never gives it back.
+//
+// Make it return the string in upper case with a `!` at the end, the way
+// `take_and_give_back` does in ch04/ownership_intro.
+//
+// Run `myrust exercise hint ownership2` if you get stuck.
+
+fn shout(s: String) {
+    let mut s = s.to_uppercase();
+    s.push('!');
+}
+
+fn shout_twice(s: String) -> String {
+    let s = shout(s);
+    shout(s)
+}
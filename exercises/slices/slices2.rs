@@ -0,0 +1,10 @@
+// slices2: `last_word` should return the last word of `s`, as a slice of `s`.
+//
+// Like `first_word`, it must not allocate a new `String`.
+//
+// Run `myrust exercise hint slices2` if you get stuck.
+
+fn last_word(s: &str) -> &str {
+    // the whole string is only right when there is a single word
+    s
+}
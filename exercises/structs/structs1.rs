@@ -0,0 +1,18 @@
+// structs1: a rectangle can be turned by 90 degrees to fit in another one.
+//
+// `can_hold` is the method from ch05/rectangles. Make it also accept `other` when it fits
+// after turning it.
+//
+// Run `myrust exercise hint structs1` if you get stuck.
+
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+}
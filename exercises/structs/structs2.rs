@@ -0,0 +1,18 @@
+// structs2: `with_email` makes a copy of `user` with another email.
+//
+// The struct update syntax (`..user`) moves the fields that aren't listed, and we only
+// borrowed `user` (see `update_email` in ch05/structs).
+//
+// Run `myrust exercise hint structs2` if you get stuck.
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    active: bool,
+    username: String,
+    email: String,
+    sign_in_count: u64,
+}
+
+fn with_email(user: &User, email: String) -> User {
+    User { email, ..*user }
+}
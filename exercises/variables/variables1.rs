@@ -0,0 +1,17 @@
+// variables1: make `countdown` compile.
+//
+// `count` goes down on every turn of the loop, but a variable can't change unless it says so
+// (see `variables_mutate` in ch03/variables).
+//
+// Run `myrust exercise hint variables1` if you get stuck.
+
+fn countdown(from: u32) -> Vec<u32> {
+    let count = from;
+    let mut seen = Vec::new();
+    while count > 0 {
+        seen.push(count);
+        count -= 1;
+    }
+    seen.push(count);
+    seen
+}
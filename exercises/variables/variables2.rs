@@ -0,0 +1,12 @@
+// variables2: `indent` starts as the leading spaces of `line` and should end as their count.
+//
+// A `&str` can't be assigned a `usize`, but a new `let` can reuse the name with another type
+// (see `shadowing` in ch03/variables).
+//
+// Run `myrust exercise hint variables2` if you get stuck.
+
+fn indent_width(line: &str) -> usize {
+    let mut indent = &line[..line.len() - line.trim_start_matches(' ').len()];
+    indent = indent.len();
+    indent
+}
@@ -0,0 +1,67 @@
+// Every chapter crate of the workspace, in the order of the book. `myrust list` prints it as is.
+
+use common::{Chapter, Demo, Reporter};
+
+pub const CHAPTERS: &[&Chapter] = &[
+    &variables::CHAPTER,
+    &data_types::CHAPTER,
+    &functions::CHAPTER,
+    &control_flow::CHAPTER,
+    &ownership_intro::CHAPTER,
+    &borrowing::CHAPTER,
+    &slice::CHAPTER,
+    &structs::CHAPTER,
+    &rectangles::CHAPTER,
+    &enums::CHAPTER,
+    &match_demo::CHAPTER,
+    &if_let_demo::CHAPTER,
+];
+
+// What `myrust run` was asked to run: a whole chapter or one demo of it.
+pub enum Target {
+    Chapter(&'static Chapter),
+    Demo(&'static Demo),
+}
+
+impl Target {
+    pub fn run(&self, out: &mut dyn Reporter) {
+        match self {
+            Target::Chapter(chapter) => chapter.run_all(out),
+            Target::Demo(demo) => demo.run_with(out),
+        }
+    }
+}
+
+// Accepts `ch05/rectangles`, `rectangles`, `rectangles::can_hold` or just `can_hold`
+// when the demo name is unique in the workspace.
+pub fn resolve(target: &str) -> Result<Target, String> {
+    if let Some((package, name)) = target.split_once("::") {
+        let chapter = find_chapter(package)?;
+        return match chapter.demo(name) {
+            Some(demo) => Ok(Target::Demo(demo)),
+            None => Err(format!("`{}` has no demo named `{name}`", chapter.path)),
+        };
+    }
+    if let Ok(chapter) = find_chapter(target) {
+        return Ok(Target::Chapter(chapter));
+    }
+
+    let mut found = CHAPTERS.iter().filter_map(|chapter| chapter.demo(target));
+    match (found.next(), found.next()) {
+        (Some(demo), None) => Ok(Target::Demo(demo)),
+        (Some(_), Some(_)) => Err(format!(
+            "`{target}` is ambiguous, use `<package>::{target}` instead"
+        )),
+        (None, _) => Err(format!(
+            "no chapter or demo named `{target}`, try `myrust list`"
+        )),
+    }
+}
+
+fn find_chapter(name: &str) -> Result<&'static Chapter, String> {
+    CHAPTERS
+        .iter()
+        .copied()
+        .find(|chapter| chapter.path == name || chapter.package == name)
+        .ok_or_else(|| format!("no chapter named `{name}`, try `myrust list`"))
+}
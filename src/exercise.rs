@@ -0,0 +1,275 @@
+// Exercises in the style of rustlings: small broken programs under `exercises/<chapter>/`,
+// each with a hidden test in `exercises/.hidden/`. An exercise is done when it compiles and its
+// hidden test passes; `myrust watch` checks the current one again every time it is saved and
+// moves on to the next when it passes. Progress is kept in `.myrust-progress` at the root.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub struct Exercise {
+    pub name: &'static str,
+    pub chapter: &'static str,
+    pub hint: &'static str,
+}
+
+// In the order they are meant to be done.
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "variables1",
+        chapter: "variables",
+        hint: "Declare `count` with `let mut` so the loop can change it.",
+    },
+    Exercise {
+        name: "variables2",
+        chapter: "variables",
+        hint: "Drop the `mut` and write `let indent = indent.len();`: the second `let` is a new variable that shadows the first one.",
+    },
+    Exercise {
+        name: "ownership1",
+        chapter: "ownership",
+        hint: "`first` can get its own copy of the heap data with `name.clone()`.",
+    },
+    Exercise {
+        name: "ownership2",
+        chapter: "ownership",
+        hint: "Give `shout` a `-> String` return type and end it with `s` (no semicolon), so the value moves back to the caller.",
+    },
+    Exercise {
+        name: "slices1",
+        chapter: "slices",
+        hint: "`u8` has an `is_ascii_whitespace` method that knows about spaces, tabs and newlines.",
+    },
+    Exercise {
+        name: "slices2",
+        chapter: "slices",
+        hint: "`s.rfind(' ')` gives the index of the last space; the word starts one byte after it.",
+    },
+    Exercise {
+        name: "structs1",
+        chapter: "structs",
+        hint: "Check the two ways `other` can fit: as it is, and with its width and height swapped.",
+    },
+    Exercise {
+        name: "structs2",
+        chapter: "structs",
+        hint: "`..user.clone()` takes the other fields from a clone, which the new `User` can own.",
+    },
+    Exercise {
+        name: "enums1",
+        chapter: "enums",
+        hint: "Add a `None => None` arm.",
+    },
+    Exercise {
+        name: "enums2",
+        chapter: "enums",
+        hint: "`let Coin::Quarter(state) = coin else { return None; };`",
+    },
+];
+
+pub fn find(name: &str) -> Result<&'static Exercise, String> {
+    EXERCISES
+        .iter()
+        .find(|exercise| exercise.name == name)
+        .ok_or_else(|| format!("no exercise named `{name}`, see `myrust exercise list`"))
+}
+
+impl Exercise {
+    // the file to fix
+    pub fn path(&self, root: &Path) -> PathBuf {
+        root.join("exercises")
+            .join(self.chapter)
+            .join(format!("{}.rs", self.name))
+    }
+
+    pub fn hidden_test(&self, root: &Path) -> PathBuf {
+        root.join("exercises/.hidden")
+            .join(format!("{}.rs", self.name))
+    }
+}
+
+pub enum Outcome {
+    // the output of rustc or of the tests
+    CompileError(String),
+    TestsFailed(String),
+    Passed(String),
+}
+
+// Builds `source` (the exercise file, or another version of it) together with the hidden test
+// and runs the test. `include!` keeps rustc pointing at `source` in its errors.
+pub fn check(
+    root: &Path,
+    exercise: &Exercise,
+    source: &Path,
+    build_dir: &Path,
+) -> io::Result<Outcome> {
+    fs::create_dir_all(build_dir)?;
+    let wrapper = build_dir.join(format!("{}.rs", exercise.name));
+    let binary = build_dir.join(exercise.name);
+    fs::write(
+        &wrapper,
+        format!(
+            "include!({:?});\n\n#[cfg(test)]\nmod hidden {{\n    use super::*;\n    include!({:?});\n}}\n",
+            fs::canonicalize(source)?,
+            fs::canonicalize(exercise.hidden_test(root))?,
+        ),
+    )?;
+
+    let build = Command::new(rustc())
+        .args(["--edition", "2024", "--test", "-o"])
+        .arg(&binary)
+        .arg(&wrapper)
+        .output()?;
+    if !build.status.success() {
+        return Ok(Outcome::CompileError(
+            String::from_utf8_lossy(&build.stderr).into_owned(),
+        ));
+    }
+
+    let test = Command::new(&binary).arg("--quiet").output()?;
+    let output = String::from_utf8_lossy(&test.stdout).into_owned();
+    Ok(if test.status.success() {
+        Outcome::Passed(output)
+    } else {
+        Outcome::TestsFailed(output)
+    })
+}
+
+fn rustc() -> String {
+    std::env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"))
+}
+
+// The names of the exercises done so far, one per line in `<root>/.myrust-progress`.
+pub struct Progress {
+    path: PathBuf,
+    done: Vec<String>,
+}
+
+impl Progress {
+    pub fn load(root: &Path) -> Result<Progress, String> {
+        let path = root.join(".myrust-progress");
+        let done = match fs::read_to_string(&path) {
+            Ok(text) => text.lines().map(str::to_string).collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(format!("failed to read `{}`: {err}", path.display())),
+        };
+        Ok(Progress { path, done })
+    }
+
+    pub fn is_done(&self, exercise: &Exercise) -> bool {
+        self.done.iter().any(|name| name == exercise.name)
+    }
+
+    // the first exercise that is not done yet
+    pub fn current(&self) -> Option<&'static Exercise> {
+        EXERCISES.iter().find(|exercise| !self.is_done(exercise))
+    }
+
+    pub fn mark_done(&mut self, exercise: &Exercise) -> Result<(), String> {
+        if !self.is_done(exercise) {
+            self.done.push(exercise.name.to_string());
+        }
+        self.save()
+    }
+
+    pub fn reset(&mut self, exercise: &Exercise) -> Result<(), String> {
+        self.done.retain(|name| name != exercise.name);
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let mut text = self.done.join("\n");
+        text.push('\n');
+        fs::write(&self.path, text)
+            .map_err(|err| format!("failed to write `{}`: {err}", self.path.display()))
+    }
+}
+
+pub fn list(root: &Path) -> Result<(), String> {
+    let progress = Progress::load(root)?;
+    let current = progress.current().map(|exercise| exercise.name);
+    for exercise in EXERCISES {
+        let status = if progress.is_done(exercise) {
+            "done"
+        } else if Some(exercise.name) == current {
+            "current"
+        } else {
+            "pending"
+        };
+        println!(
+            "{:<12} {:<8} exercises/{}/{}.rs",
+            exercise.name, status, exercise.chapter, exercise.name
+        );
+    }
+    let done = EXERCISES.iter().filter(|e| progress.is_done(e)).count();
+    println!("\n{done} of {} done", EXERCISES.len());
+    Ok(())
+}
+
+// Checks one exercise and records it when it passes. Returns whether it passed.
+pub fn run(root: &Path, exercise: &Exercise) -> Result<bool, String> {
+    let path = exercise.path(root);
+    let outcome = check(root, exercise, &path, &root.join("target/exercises"))
+        .map_err(|err| format!("failed to check `{}`: {err}", exercise.name))?;
+    let passed = match outcome {
+        Outcome::CompileError(output) => {
+            print!("{output}");
+            println!("`{}` doesn't compile yet.", exercise.name);
+            false
+        }
+        Outcome::TestsFailed(output) => {
+            print!("{output}");
+            println!("`{}` compiles, but its tests fail.", exercise.name);
+            false
+        }
+        Outcome::Passed(output) => {
+            print!("{output}");
+            println!("`{}` passes!", exercise.name);
+            true
+        }
+    };
+    let mut progress = Progress::load(root)?;
+    if passed {
+        progress.mark_done(exercise)?;
+    } else {
+        progress.reset(exercise)?;
+    }
+    Ok(passed)
+}
+
+// Checks the current exercise, then again every time its file changes, until all are done.
+pub fn watch(root: &Path) -> Result<(), String> {
+    loop {
+        let Some(exercise) = Progress::load(root)?.current() else {
+            println!("Every exercise is done, well done!");
+            return Ok(());
+        };
+        println!("\n==> {} (exercises/{})", exercise.name, exercise.chapter);
+        if run(root, exercise)? {
+            continue;
+        }
+        let path = exercise.path(root);
+        println!(
+            "Waiting for {} to change (`myrust exercise hint {}` for a hint, Ctrl-C to stop)...",
+            path.display(),
+            exercise.name
+        );
+        wait_for_change(&path);
+    }
+}
+
+// There is no file watcher in std, so look at the modification time twice a second.
+fn wait_for_change(path: &Path) {
+    let modified = |path: &Path| {
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH)
+    };
+    let before = modified(path);
+    while modified(path) == before {
+        thread::sleep(Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,245 @@
+// The study guide: the notes of every chapter, in the order they appear in the sources, each
+// followed by the code it talks about. `myrust guide` writes one Markdown file per chapter
+// (`ch03.md`, `ch04.md`, ...) and a single `study-guide.html` with the code colored.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use common::Chapter;
+
+use crate::catalog::CHAPTERS;
+use crate::lexer::{self, TokenKind};
+use crate::source::{self, Note, SourceFile};
+
+// The notes of one crate, e.g. `ch04/slice`.
+pub struct Section {
+    pub chapter: &'static Chapter,
+    pub notes: Vec<(String, Note)>,
+}
+
+// Reads the notes of every chapter crate under `root`.
+pub fn collect(root: &Path) -> Result<Vec<Section>, String> {
+    CHAPTERS
+        .iter()
+        .map(|&chapter| {
+            let files = source::load(root, chapter)
+                .map_err(|err| format!("failed to read `{}`: {err}", chapter.path))?;
+            let notes = files
+                .iter()
+                .flat_map(|file: &SourceFile| {
+                    file.notes()
+                        .into_iter()
+                        .map(|note| (file.path.clone(), note))
+                })
+                .collect();
+            Ok(Section { chapter, notes })
+        })
+        .collect()
+}
+
+pub fn write(root: &Path, out_dir: &Path) -> Result<Vec<String>, String> {
+    let sections = collect(root)?;
+    fs::create_dir_all(out_dir)
+        .map_err(|err| format!("failed to create `{}`: {err}", out_dir.display()))?;
+
+    let mut written = Vec::new();
+    let mut save = |name: String, contents: String| {
+        let path = out_dir.join(&name);
+        fs::write(&path, contents)
+            .map_err(|err| format!("failed to write `{}`: {err}", path.display()))?;
+        written.push(path.display().to_string());
+        Ok::<_, String>(())
+    };
+    for (part, sections) in by_part(&sections) {
+        save(format!("{part}.md"), markdown(part, &sections))?;
+    }
+    save(String::from("study-guide.html"), html(&sections))?;
+    Ok(written)
+}
+
+// `ch03`, `ch04`, ... with the crates of each, in order
+fn by_part(sections: &[Section]) -> Vec<(&str, Vec<&Section>)> {
+    let mut parts: Vec<(&str, Vec<&Section>)> = Vec::new();
+    for section in sections {
+        let part = section.chapter.path.split('/').next().unwrap();
+        match parts.last_mut() {
+            Some((last, sections)) if *last == part => sections.push(section),
+            _ => parts.push((part, vec![section])),
+        }
+    }
+    parts
+}
+
+fn heading(file: &str, note: &Note) -> String {
+    match &note.item {
+        Some(item) => format!("`{} {}` ({file}:{})", item.kind, item.name, note.line),
+        None => format!("Notes ({file}:{})", note.line),
+    }
+}
+
+pub fn markdown(part: &str, sections: &[&Section]) -> String {
+    let mut md = format!("# {part}\n");
+    for section in sections {
+        let chapter = section.chapter;
+        let _ = write!(md, "\n## {} - {}\n", chapter.path, chapter.description);
+        for (file, note) in &section.notes {
+            let _ = write!(md, "\n### {}\n\n{}\n", heading(file, note), note.text);
+            if let Some(item) = &note.item {
+                let _ = write!(md, "\n```rust\n{}\n```\n", item.code);
+            }
+        }
+    }
+    md
+}
+
+pub fn html(sections: &[Section]) -> String {
+    let mut html = String::from(HTML_HEAD);
+    html.push_str("<nav><ul>\n");
+    for section in sections {
+        let path = section.chapter.path;
+        let _ = writeln!(html, "<li><a href=\"#{}\">{path}</a></li>", anchor(path));
+    }
+    html.push_str("</ul></nav>\n<main>\n");
+
+    for section in sections {
+        let chapter = section.chapter;
+        let _ = writeln!(
+            html,
+            "<section id=\"{}\">\n<h2>{} <small>{}</small></h2>",
+            anchor(chapter.path),
+            escape(chapter.path),
+            inline(chapter.description)
+        );
+        for (file, note) in &section.notes {
+            let _ = writeln!(html, "<h3>{}</h3>", inline(&heading(file, note)));
+            let _ = writeln!(html, "<div class=\"note\">{}</div>", note_html(&note.text));
+            if let Some(item) = &note.item {
+                let _ = writeln!(
+                    html,
+                    "<pre class=\"code\"><code>{}</code></pre>",
+                    highlight(&item.code)
+                );
+            }
+        }
+        html.push_str("</section>\n");
+    }
+    html.push_str("</main>\n</body>\n</html>\n");
+    html
+}
+
+fn anchor(path: &str) -> String {
+    path.replace('/', "-")
+}
+
+// The notes are written like Markdown: keep the line breaks, turn fences into code blocks
+// and `**bold**` / `code` into tags.
+fn note_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut in_fence = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            out.push_str(if in_fence {
+                "</code></pre>"
+            } else {
+                "<pre class=\"code\"><code>"
+            });
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            out.push_str(&highlight(line));
+        } else {
+            out.push_str(&inline(line));
+        }
+        out.push('\n');
+    }
+    if in_fence {
+        out.push_str("</code></pre>");
+    }
+    out
+}
+
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut bold = false;
+    let mut code = false;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if !code && rest.starts_with("**") {
+            out.push_str(if bold { "</strong>" } else { "<strong>" });
+            bold = !bold;
+            rest = &rest[2..];
+            continue;
+        }
+        if c == '`' {
+            out.push_str(if code { "</code>" } else { "<code>" });
+            code = !code;
+        } else {
+            out.push_str(&escape(&c.to_string()));
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    if code {
+        out.push_str("</code>");
+    }
+    if bold {
+        out.push_str("</strong>");
+    }
+    out
+}
+
+pub fn highlight(code: &str) -> String {
+    let mut out = String::new();
+    let tokens = lexer::tokenize(code);
+    for (i, token) in tokens.iter().enumerate() {
+        let text = escape(&code[token.start..token.end]);
+        let next_is_bang = tokens
+            .get(i + 1)
+            .is_some_and(|next| &code[next.start..next.end] == "!");
+        let class = match token.kind {
+            TokenKind::Keyword => Some("kw"),
+            TokenKind::Str | TokenKind::Char => Some("str"),
+            TokenKind::LineComment | TokenKind::BlockComment => Some("comment"),
+            TokenKind::Number => Some("num"),
+            TokenKind::Lifetime => Some("lifetime"),
+            TokenKind::Ident if next_is_bang => Some("macro"),
+            TokenKind::Ident if text.starts_with(char::is_uppercase) => Some("type"),
+            _ => None,
+        };
+        match class {
+            Some(class) => {
+                let _ = write!(out, "<span class=\"{class}\">{text}</span>");
+            }
+            None => out.push_str(&text),
+        }
+    }
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_HEAD: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>myrust study guide</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 0; display: flex; color: #222; }
+nav { width: 14rem; padding: 1rem; border-right: 1px solid #ddd; position: sticky; top: 0; height: 100vh; overflow-y: auto; }
+nav ul { list-style: none; padding: 0; }
+main { max-width: 60rem; padding: 1rem 2rem; }
+h2 small { font-weight: normal; color: #666; }
+.note { white-space: pre-wrap; line-height: 1.4; }
+pre.code { background: #f6f8fa; padding: 0.75rem; overflow-x: auto; white-space: pre; }
+.kw { color: #a626a4; } .str { color: #50a14f; } .comment { color: #a0a1a7; font-style: italic; }
+.num { color: #986801; } .lifetime { color: #c18401; } .macro { color: #4078f2; } .type { color: #0184bc; }
+</style>
+</head>
+<body>
+"#;
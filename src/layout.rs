@@ -0,0 +1,708 @@
+// What the values of the Copy vs move demos look like in memory: the size and alignment of their
+// type, whether it is `Copy`, the bytes they take on the stack, and the heap buffer they own, laid
+// out like Figure 4-1 of the Book.
+//
+// Only the bytes of fields, and the ones that tell an enum's variants apart, are read. Padding
+// may be uninitialized, and reading it as numbers is undefined behavior: it is drawn as `..`.
+// The layout of a type without `#[repr(C)]` is up to the compiler, so the offsets of fields are
+// found from their addresses, not assumed. Only the tag or niche of an enum has no address: it is
+// where rustc puts it, the bytes before the fields of a tagged enum and the capacity of the
+// `String` of a niche one, which is written with every value of the enum. `Offsets::checked`
+// makes sure of that first, from the sizes of the types and values whose tag or niche is known,
+// and `myrust layout` refuses to read anything when they don't match.
+
+use std::fmt::Write as _;
+use std::marker::PhantomData;
+
+use enums::Message;
+use rectangles::Rectangle;
+
+/// One value and how it is laid out.
+pub struct Layout {
+    /// The name of the type, what `myrust layout <name>` looks for.
+    pub name: &'static str,
+    /// How the value was made.
+    pub expr: &'static str,
+    pub size: usize,
+    pub align: usize,
+    pub copy: bool,
+    /// The fields, by offset.
+    pub fields: Vec<Field>,
+    /// The bytes a pointer of the value points to, if it has one.
+    pub target: Option<Target>,
+    pub note: Option<String>,
+}
+
+/// A field and its bytes on the stack.
+pub struct Field {
+    pub name: String,
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+    /// The bytes read as the type of the field.
+    pub value: String,
+}
+
+/// The bytes a `String` or a `&str` points to.
+pub struct Target {
+    pub address: usize,
+    /// On the heap, or in the binary for a string literal.
+    pub heap: bool,
+    pub bytes: Vec<u8>,
+    /// The capacity past the bytes, for a heap buffer.
+    pub spare: usize,
+}
+
+/// Types whose bytes are all initialized, whatever their value, so they can be read as `u8`s.
+///
+/// # Safety
+///
+/// The type has no padding and no uninitialized bytes.
+unsafe trait Plain: Sized {
+    fn show(&self) -> String;
+}
+
+macro_rules! plain {
+    ($($t:ty),*) => {$(
+        // SAFETY: a primitive number, every byte is part of its value.
+        unsafe impl Plain for $t {
+            fn show(&self) -> String {
+                format!("{self:?}")
+            }
+        }
+    )*};
+}
+plain!(i32, u32, u8, f64);
+
+// A word of a pointer and integers, which ones is found from their values.
+#[derive(Clone, Copy)]
+struct Word(usize);
+
+// SAFETY: a `usize`.
+unsafe impl Plain for Word {
+    fn show(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+// `Probe::<T>::is_copy` is the inherent method when `T: Copy`, and the `NotCopy` one, which the
+// compiler only looks at next, otherwise. It only works for a type written out, so in a macro.
+struct Probe<T>(PhantomData<T>);
+
+trait NotCopy {
+    fn is_copy(&self) -> bool {
+        false
+    }
+}
+
+impl<T> NotCopy for Probe<T> {}
+
+impl<T: Copy> Probe<T> {
+    fn is_copy(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! is_copy {
+    ($t:ty) => {
+        Probe::<$t>(PhantomData).is_copy()
+    };
+}
+
+// Collects the fields of one value, at their offset from the start of it.
+struct Fields {
+    base: *const u8,
+    fields: Vec<Field>,
+    target: Option<Target>,
+}
+
+impl Fields {
+    fn new<T>(value: &T) -> Self {
+        Fields {
+            base: value as *const T as *const u8,
+            fields: Vec::new(),
+            target: None,
+        }
+    }
+
+    fn field<F: Plain>(&mut self, name: &str, field: &F) {
+        self.field_as(name, field, field.show());
+    }
+
+    fn field_as<F: Plain>(&mut self, name: &str, field: &F, value: String) {
+        // SAFETY: `F: Plain`, so the `size_of::<F>()` bytes behind `field` are initialized.
+        let bytes =
+            unsafe { std::slice::from_raw_parts(field as *const F as *const u8, size_of::<F>()) };
+        self.fields.push(Field {
+            name: name.to_string(),
+            offset: field as *const F as usize - self.base as usize,
+            bytes: bytes.to_vec(),
+            value,
+        });
+    }
+
+    // The pointer, length and capacity of a `String`, in whatever order std keeps them.
+    fn string(&mut self, prefix: &str, s: &String) {
+        let names = string_words();
+        for (word, name) in words_of(s).iter().zip(names) {
+            self.word(prefix, name, word, s.as_ptr());
+        }
+        self.target = Some(Target {
+            address: s.as_ptr() as usize,
+            heap: s.capacity() > 0,
+            bytes: s.as_bytes().to_vec(),
+            spare: s.capacity() - s.len(),
+        });
+    }
+
+    // The pointer and length of a `&str`.
+    fn str(&mut self, s: &&str) {
+        const _: () = assert!(size_of::<&str>() == 2 * size_of::<usize>());
+        // SAFETY: a `&str` is a pointer and a length, two words without padding.
+        let words = unsafe { &*(s as *const &str as *const [Word; 2]) };
+        for word in words {
+            let name = if word.0 == s.as_ptr() as usize {
+                "ptr"
+            } else {
+                "len"
+            };
+            self.word("", name, word, s.as_ptr());
+        }
+        self.target = Some(Target {
+            address: s.as_ptr() as usize,
+            heap: false,
+            bytes: s.as_bytes().to_vec(),
+            spare: 0,
+        });
+    }
+
+    fn word(&mut self, prefix: &str, name: &str, word: &Word, ptr: *const u8) {
+        let value = if word.0 == ptr as usize {
+            format!("{:#x}", word.0)
+        } else {
+            word.show()
+        };
+        self.field_as(&format!("{prefix}{name}"), word, value);
+    }
+
+    // The bytes at `offset..offset + len` that tell which variant an enum is: a tag of their own,
+    // or a niche, a value a field of another variant never has. `discriminant` is the one of the
+    // variant, by declaration order. In the variant with the niche field, the field says it.
+    fn discriminant(
+        &mut self,
+        (offset, len): (usize, usize),
+        niche: bool,
+        discriminant: usize,
+        variant: &str,
+    ) {
+        let says = format!("discriminant {discriminant}, {variant}");
+        if let Some(field) = self.fields.iter_mut().find(|field| field.offset == offset) {
+            field.value = format!("{}, so {says}", field.value);
+            return;
+        }
+        // SAFETY: `offset` and `len` come from `Offsets::checked`, which read them back from
+        // values of the same enum: the tag or niche is there, inside the value, in every variant.
+        let bytes = unsafe { std::slice::from_raw_parts(self.base.add(offset), len) };
+        let raw = number(bytes);
+        let (name, value) = if niche {
+            ("niche", format!("{raw:#x}: {says}"))
+        } else {
+            ("tag", format!("{raw}: {says}"))
+        };
+        self.fields.push(Field {
+            name: name.to_string(),
+            offset,
+            bytes: bytes.to_vec(),
+            value,
+        });
+    }
+
+    fn into_layout<T>(
+        mut self,
+        name: &'static str,
+        expr: &'static str,
+        copy: bool,
+        note: Option<String>,
+    ) -> Layout {
+        self.fields.sort_by_key(|field| field.offset);
+        Layout {
+            name,
+            expr,
+            size: size_of::<T>(),
+            align: align_of::<T>(),
+            copy,
+            fields: self.fields,
+            target: self.target,
+            note,
+        }
+    }
+}
+
+fn words_of(s: &String) -> &[Word; 3] {
+    const _: () = assert!(size_of::<String>() == 3 * size_of::<usize>());
+    // SAFETY: a `String` is a pointer and two integers, three words without padding.
+    unsafe { &*(s as *const String as *const [Word; 3]) }
+}
+
+// The names of the words of every `String`, found from one whose pointer, length and capacity
+// all differ: the values of a `String` with a length equal to its capacity can't tell.
+fn string_words() -> [&'static str; 3] {
+    let mut probe = String::with_capacity(8);
+    probe.push('x');
+    words_of(&probe).map(|word| {
+        if word.0 == probe.as_ptr() as usize {
+            "ptr"
+        } else if word.0 == probe.len() {
+            "len"
+        } else if word.0 == probe.capacity() {
+            "capacity"
+        } else {
+            "?"
+        }
+    })
+}
+
+// Bytes of a number in the order of this target.
+fn number(bytes: &[u8]) -> u128 {
+    let fold = |raw: u128, &byte: &u8| raw << 8 | u128::from(byte);
+    if cfg!(target_endian = "little") {
+        bytes.iter().rev().fold(0, fold)
+    } else {
+        bytes.iter().fold(0, fold)
+    }
+}
+
+// Where the bytes are that aren't in a field: (offset, length) of the tag of `Option<i32>`, and
+// of the niches of `Option<String>` and `Message`.
+struct Offsets {
+    option_i32: (usize, usize),
+    option_string: (usize, usize),
+    message: (usize, usize),
+}
+
+impl Offsets {
+    // The offsets rustc is assumed to use, once the sizes of the types and values with a known
+    // tag or niche agree with them, see the top of the file.
+    fn checked() -> Result<Offsets, String> {
+        let refuse = |what: &str| {
+            Err(format!(
+                "refusing to read the layouts: {what}, which isn't how this tool expects rustc \
+                 to lay it out"
+            ))
+        };
+        let mut words = string_words();
+        words.sort();
+        if words != ["capacity", "len", "ptr"] {
+            return refuse("a `String` isn't a pointer, a length and a capacity");
+        }
+
+        // a tag before the value, enlarged to the size of the `i32` it is aligned like
+        let probe = Some(0i32);
+        let option_i32 = (0, offset_in(&probe, probe.as_ref().unwrap()));
+        if (
+            size_of::<Option<i32>>(),
+            align_of::<Option<i32>>(),
+            option_i32.1,
+        ) != (2 * size_of::<i32>(), align_of::<i32>(), size_of::<i32>())
+        {
+            return refuse("`Option<i32>` has no 4-byte tag before its value");
+        }
+        // SAFETY: the sizes say the 4 bytes before the value are the tag.
+        let tags = unsafe { [read(&None::<i32>, option_i32), read(&Some(7), option_i32)] };
+        if tags != [0, 1] {
+            return refuse("the tag of `Option<i32>` isn't 0 for `None` and 1 for `Some`");
+        }
+
+        // the capacity of the `String` of the variant that has one, which is never above
+        // `isize::MAX`, so the other variants are kept in the values above
+        let niche_above = |niches: &[u128]| {
+            niches.iter().all(|&niche| niche > isize::MAX as u128)
+                && niches
+                    .iter()
+                    .enumerate()
+                    .all(|(i, a)| !niches[..i].contains(a))
+        };
+        let probe = Some(String::with_capacity(3));
+        let option_string = capacity(&probe, probe.as_ref().unwrap());
+        if size_of::<Option<String>>() != size_of::<String>() {
+            return refuse("`Option<String>` is bigger than a `String`, so it has a tag");
+        }
+        // SAFETY: an `Option<String>` is as big as a `String`, so all its bytes are the
+        // `String` of `Some`, or the niche of `None` in the place of its capacity.
+        let niches = unsafe {
+            [
+                read(&probe, option_string),
+                read(&None::<String>, option_string),
+            ]
+        };
+        if niches[0] != 3 || !niche_above(&niches[1..]) {
+            return refuse("`None::<String>` isn't kept in the capacity of the `String`");
+        }
+
+        let probe = Message::Write(String::with_capacity(5));
+        let Message::Write(s) = &probe else {
+            unreachable!()
+        };
+        let message = capacity(&probe, s);
+        if size_of::<Message>() != size_of::<String>() {
+            return refuse("`Message` is bigger than a `String`, so it has a tag");
+        }
+        let others = [
+            Message::Quit,
+            Message::Move { x: 0, y: 0 },
+            Message::ChangeColor(0, 0, 0),
+        ];
+        // SAFETY: a `Message` is as big as a `String`, which only `Write` has room for, so the
+        // capacity of its `String` is where the other variants are told apart.
+        let (write, niches) = unsafe {
+            (
+                read(&probe, message),
+                others.map(|other| read(&other, message)),
+            )
+        };
+        if write != 5 || !niche_above(&niches) {
+            return refuse("the variants of `Message` aren't kept in the capacity of `Write`");
+        }
+        Ok(Offsets {
+            option_i32,
+            option_string,
+            message,
+        })
+    }
+}
+
+// The number at `offset..offset + len` of `value`.
+//
+// # Safety
+//
+// Those bytes are inside `value` and initialized.
+unsafe fn read<E>(value: &E, (offset, len): (usize, usize)) -> u128 {
+    assert!(offset + len <= size_of::<E>() && len <= size_of::<u128>());
+    // SAFETY: in bounds, just checked, and initialized, says the caller.
+    number(unsafe { std::slice::from_raw_parts((value as *const E as *const u8).add(offset), len) })
+}
+
+// `specimen!(Type, "name", value, |fields, value| ...)`: the layout of `value`, a `Type`.
+macro_rules! specimen {
+    ($t:ty, $name:expr, $value:expr, |$fields:ident, $v:ident| $body:expr) => {
+        specimen!($t, $name, $value, |$fields, $v| $body, None)
+    };
+    ($t:ty, $name:expr, $value:expr, |$fields:ident, $v:ident| $body:expr, $note:expr) => {{
+        let $v: $t = $value;
+        let mut $fields = Fields::new(&$v);
+        $body;
+        $fields.into_layout::<$t>($name, stringify!($value), is_copy!($t), $note)
+    }};
+}
+
+/// The layouts `myrust layout` shows: the values of `take_copy` and
+/// `take_onwership_from_something`, then structs and enums of ch05 and ch06. None of them when
+/// rustc lays out the enums or `String` in a way this tool doesn't know.
+pub fn specimens() -> Result<Vec<Layout>, String> {
+    let offsets = Offsets::checked()?;
+    Ok(vec![
+        specimen!(i32, "i32", 10, |f, x| f.field("x", &x)),
+        specimen!((i32, f64, u8), "tuple", (500, 6.4, 1), |f, t| {
+            f.field(".0", &t.0);
+            f.field(".1", &t.1);
+            f.field(".2", &t.2);
+        }),
+        specimen!([i32; 5], "array", [1, 2, 3, 4, 5], |f, a| {
+            for (i, x) in a.iter().enumerate() {
+                f.field(&format!("[{i}]"), x);
+            }
+        }),
+        specimen!(&str, "&str", "Ioannis", |f, s| f.str(&s)),
+        specimen!(
+            String,
+            "String",
+            {
+                let mut s = String::with_capacity(8);
+                s.push_str("hello");
+                s
+            },
+            |f, s| f.string("", &s)
+        ),
+        specimen!(
+            Rectangle,
+            "Rectangle",
+            Rectangle {
+                width: 30,
+                height: 50
+            },
+            |f, r| {
+                f.field("width", &r.width);
+                f.field("height", &r.height);
+            }
+        ),
+        message(Message::Quit, "Message::Quit", offsets.message),
+        message(
+            Message::Move { x: 10, y: 20 },
+            "Message::Move { x: 10, y: 20 }",
+            offsets.message,
+        ),
+        message(
+            Message::Write(String::from("hello")),
+            "Message::Write(String::from(\"hello\"))",
+            offsets.message,
+        ),
+        message(
+            Message::ChangeColor(0, 160, 255),
+            "Message::ChangeColor(0, 160, 255)",
+            offsets.message,
+        ),
+        specimen!(
+            Option<String>,
+            "Option<String>",
+            Some(String::from("foo")),
+            |f, o| option_string(&mut f, &o, offsets.option_string),
+            Some(enum_note::<Option<String>, String>())
+        ),
+        specimen!(
+            Option<String>,
+            "Option<String>",
+            None,
+            |f, o| option_string(&mut f, &o, offsets.option_string),
+            Some(enum_note::<Option<String>, String>())
+        ),
+        specimen!(
+            Option<i32>,
+            "Option<i32>",
+            Some(7),
+            |f, o| option_i32(&mut f, &o, offsets.option_i32),
+            Some(enum_note::<Option<i32>, i32>())
+        ),
+        specimen!(
+            Option<i32>,
+            "Option<i32>",
+            None::<i32>,
+            |f, o| option_i32(&mut f, &o, offsets.option_i32),
+            Some(enum_note::<Option<i32>, i32>())
+        ),
+    ])
+}
+
+const OPTION_VARIANTS: [&str; 2] = ["None", "Some"];
+
+fn option_string(fields: &mut Fields, o: &Option<String>, niche: (usize, usize)) {
+    if let Some(s) = o {
+        fields.string("Some.", s);
+    }
+    fields.discriminant(
+        niche,
+        true,
+        o.is_some() as usize,
+        OPTION_VARIANTS[o.is_some() as usize],
+    );
+}
+
+fn option_i32(fields: &mut Fields, o: &Option<i32>, tag: (usize, usize)) {
+    if let Some(x) = o {
+        fields.field("Some.0", x);
+    }
+    fields.discriminant(
+        tag,
+        false,
+        o.is_some() as usize,
+        OPTION_VARIANTS[o.is_some() as usize],
+    );
+}
+
+// Where the capacity of `s`, a `String` in `probe`, is.
+fn capacity<E>(probe: &E, s: &String) -> (usize, usize) {
+    let word = string_words()
+        .iter()
+        .position(|&name| name == "capacity")
+        .unwrap();
+    (
+        offset_in(probe, s) + word * size_of::<usize>(),
+        size_of::<usize>(),
+    )
+}
+
+fn offset_in<E, F>(value: &E, field: &F) -> usize {
+    field as *const F as usize - value as *const E as usize
+}
+
+// The variants of `Message` differ in their fields, so each one is shown.
+fn message(value: Message, expr: &'static str, niche: (usize, usize)) -> Layout {
+    let mut fields = Fields::new(&value);
+    let discriminant = match &value {
+        Message::Quit => 0,
+        Message::Move { x, y } => {
+            fields.field("x", x);
+            fields.field("y", y);
+            1
+        }
+        Message::Write(s) => {
+            fields.string("0.", s);
+            2
+        }
+        Message::ChangeColor(r, g, b) => {
+            fields.field("0", r);
+            fields.field("1", g);
+            fields.field("2", b);
+            3
+        }
+    };
+    let variant = ["Quit", "Move", "Write", "ChangeColor"][discriminant];
+    fields.discriminant(niche, true, discriminant, variant);
+    fields.into_layout::<Message>(
+        "Message",
+        expr,
+        is_copy!(Message),
+        Some(enum_note::<Message, String>()),
+    )
+}
+
+// How an enum tells its variants apart, from its size and the size of its biggest variant.
+fn enum_note<E, Biggest>() -> String {
+    if size_of::<E>() == size_of::<Biggest>() {
+        format!(
+            "{} bytes, as many as its biggest variant: there is no room for a tag, so the \
+             variant is kept in a value the fields never have (a niche), like a capacity above \
+             `isize::MAX`",
+            size_of::<E>()
+        )
+    } else {
+        format!(
+            "{} bytes for a {}-byte variant: the rest holds a tag that says which variant it is",
+            size_of::<E>(),
+            size_of::<Biggest>()
+        )
+    }
+}
+
+/// The layout as text: the stack bytes on the left, what they point to on the right.
+pub fn render(layout: &Layout) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{} = {}", layout.name, layout.expr);
+    let _ = writeln!(
+        out,
+        "size {}, align {}, {}",
+        layout.size,
+        layout.align,
+        if layout.copy {
+            "Copy: passing it copies these bytes, the original stays valid"
+        } else {
+            "not Copy: passing it moves these bytes, the original can't be used anymore"
+        }
+    );
+
+    let (stack, ptr_row) = stack_table(layout);
+    let target = layout.target.as_ref().map(target_table).unwrap_or_default();
+    let width = stack
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    for i in 0..stack.len().max(target.len()) {
+        let left = stack.get(i).map_or("", String::as_str);
+        let gap = if ptr_row == Some(i) && !target.is_empty() {
+            " ---> "
+        } else {
+            "      "
+        };
+        let right = target.get(i).map_or("", String::as_str);
+        let line = format!("{left:<width$}{gap}{right}");
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    if let Some(note) = &layout.note {
+        let _ = writeln!(out, "{note}");
+    }
+    out
+}
+
+// The table, and the line of the pointer, where the arrow to the bytes it points to starts.
+fn stack_table(layout: &Layout) -> (Vec<String>, Option<usize>) {
+    let mut rows: Vec<[String; 4]> = Vec::new();
+    let mut ptr_row = None;
+    let mut offset = 0;
+    for field in &layout.fields {
+        if field.offset > offset {
+            rows.push(gap(offset, field.offset));
+        }
+        if field.name.ends_with("ptr") {
+            // past the border, the header and the border under it
+            ptr_row = Some(rows.len() + 3);
+        }
+        let bytes: Vec<String> = field.bytes.iter().map(|b| format!("{b:02x}")).collect();
+        rows.push([
+            field.name.clone(),
+            field.offset.to_string(),
+            bytes.join(" "),
+            field.value.clone(),
+        ]);
+        offset = field.offset + field.bytes.len();
+    }
+    if offset < layout.size {
+        rows.push(gap(offset, layout.size));
+    }
+    (table(["stack", "offset", "bytes", "value"], &rows), ptr_row)
+}
+
+// Bytes no field, tag or niche covers: padding, never read.
+fn gap(from: usize, to: usize) -> [String; 4] {
+    [
+        String::new(),
+        from.to_string(),
+        vec![".."; to - from].join(" "),
+        String::from("(not a field)"),
+    ]
+}
+
+fn target_table(target: &Target) -> Vec<String> {
+    let title = if target.heap {
+        format!("heap {:#x}", target.address)
+    } else {
+        format!("binary {:#x}", target.address)
+    };
+    let mut rows: Vec<[String; 2]> = target
+        .bytes
+        .iter()
+        .enumerate()
+        .map(|(i, &b)| {
+            let value = if b.is_ascii_graphic() || b == b' ' {
+                (b as char).to_string()
+            } else {
+                format!("{b:02x}")
+            };
+            [i.to_string(), value]
+        })
+        .collect();
+    if target.spare > 0 {
+        rows.push([
+            format!(
+                "{}..{}",
+                target.bytes.len(),
+                target.bytes.len() + target.spare
+            ),
+            String::from("(spare)"),
+        ]);
+    }
+    table([&title, "value"], &rows)
+}
+
+// A table with a header, `|` between the columns and a line around.
+fn table<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> Vec<String> {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: Vec<&str>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!(" {cell:<width$} "))
+            .collect();
+        format!("|{}|", cells.join("|"))
+    };
+    let border = format!("+{}+", widths.map(|width| "-".repeat(width + 2)).join("+"));
+    let mut lines = vec![border.clone(), line(header.to_vec()), border.clone()];
+    for row in rows {
+        lines.push(line(row.iter().map(String::as_str).collect()));
+    }
+    lines.push(border);
+    lines
+}
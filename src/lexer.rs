@@ -0,0 +1,169 @@
+// A small lexer for Rust source, good enough to find where comments, strings and items
+// start and end, and to color code in the study guide. It never fails: anything it doesn't
+// know becomes a `Punct` token.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Whitespace,
+    LineComment,
+    BlockComment,
+    Ident,
+    Keyword,
+    Lifetime,
+    Char,
+    Str,
+    Number,
+    Punct,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+pub fn tokenize(src: &str) -> Vec<Token> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let rest = &src[pos..];
+        let c = rest.chars().next().unwrap();
+        let kind = if c.is_whitespace() {
+            pos += len_while(rest, char::is_whitespace);
+            TokenKind::Whitespace
+        } else if rest.starts_with("//") {
+            pos += rest.find('\n').unwrap_or(rest.len());
+            TokenKind::LineComment
+        } else if rest.starts_with("/*") {
+            pos += block_comment_len(rest);
+            TokenKind::BlockComment
+        } else if let Some(len) = raw_string_len(rest) {
+            pos += len;
+            TokenKind::Str
+        } else if c == '"' || rest.starts_with("b\"") {
+            pos += string_len(rest);
+            TokenKind::Str
+        } else if c == '\'' || rest.starts_with("b'") {
+            match char_len(rest) {
+                Some(len) => {
+                    pos += len;
+                    TokenKind::Char
+                }
+                None => {
+                    pos += 1 + len_while(&rest[1..], is_ident_char);
+                    TokenKind::Lifetime
+                }
+            }
+        } else if c.is_ascii_digit() {
+            pos += len_while(rest, |c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+            // `0..5` is a range, not a float
+            if let Some(dots) = src[start..pos].find("..") {
+                pos = start + dots;
+            }
+            TokenKind::Number
+        } else if is_ident_char(c) {
+            pos += len_while(rest, is_ident_char);
+            if KEYWORDS.contains(&&src[start..pos]) {
+                TokenKind::Keyword
+            } else {
+                TokenKind::Ident
+            }
+        } else {
+            pos += c.len_utf8();
+            TokenKind::Punct
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: pos,
+        });
+    }
+    tokens
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn len_while(s: &str, mut pred: impl FnMut(char) -> bool) -> usize {
+    s.char_indices()
+        .find(|&(_, c)| !pred(c))
+        .map_or(s.len(), |(i, _)| i)
+}
+
+// block comments nest in Rust
+fn block_comment_len(s: &str) -> usize {
+    let mut depth = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if s[i..].starts_with("/*") {
+            depth += 1;
+            i += 2;
+        } else if s[i..].starts_with("*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += s[i..].chars().next().unwrap().len_utf8();
+        }
+    }
+    s.len()
+}
+
+fn string_len(s: &str) -> usize {
+    let open = s.find('"').unwrap();
+    let mut escaped = false;
+    for (i, c) in s[open + 1..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return open + 1 + i + 1,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+// r"..", r#".."#, br".."
+fn raw_string_len(s: &str) -> Option<usize> {
+    let after_prefix = s.strip_prefix("br").or_else(|| s.strip_prefix('r'))?;
+    let hashes = len_while(after_prefix, |c| c == '#');
+    if !after_prefix[hashes..].starts_with('"') {
+        return None;
+    }
+    let body_start = s.len() - after_prefix.len() + hashes + 1;
+    let closing = format!("\"{}", "#".repeat(hashes));
+    Some(
+        s[body_start..]
+            .find(&closing)
+            .map_or(s.len(), |end| body_start + end + closing.len()),
+    )
+}
+
+// `'a'`, `'\n'`, `b'x'`, or `None` for a lifetime like `'a`
+fn char_len(s: &str) -> Option<usize> {
+    let open = s.find('\'').unwrap();
+    let body = &s[open + 1..];
+    let mut chars = body.char_indices();
+    let (_, first) = chars.next()?;
+    if first == '\\' {
+        let close = body[1..].find('\'')?;
+        return Some(open + 1 + 1 + close + 1);
+    }
+    match chars.next() {
+        Some((i, '\'')) => Some(open + 1 + i + 1),
+        _ => None,
+    }
+}
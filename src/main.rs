@@ -1,3 +1,237 @@
-fn main() {
-    println!("Hello, world!");
+/*
+`myrust` is the launcher for every chapter in this workspace.
+
+    myrust list                      every chapter and the demos it has
+    myrust run ch05/rectangles       a whole chapter
+    myrust run rectangles::can_hold  a single demo of a chapter
+    myrust run loop_with_name        a demo, when its name is unique
+    myrust guide [--out <dir>]       the notes of every chapter as Markdown and HTML
+    myrust search "shallow copy"     the notes and functions that talk about something
+    myrust exercise list             the exercises, and which ones are done
+    myrust exercise run [<name>]     check an exercise, the current one by default
+    myrust exercise hint [<name>]    a hint for an exercise
+    myrust watch                     check the current exercise every time it is saved
+    myrust layout [<type>]           how values of the Copy and move demos sit in memory
+
+`run` prints the output of the demos as text by default. With `--json` every step of a demo
+becomes one JSON object per line, and `--output <file>` writes to a file instead of stdout.
+Demos that ask for input (`arrays_demo`) take it from `--input <line>`, then from the
+`MYRUST_INPUT` environment variable (`MYRUST_INPUT=7,3`), then from stdin.
+
+`guide` reads the `/* ... */` notes of every chapter and writes `ch03.md` ... `ch06.md` and a
+`study-guide.html` to `target/study-guide` (relative to the workspace).
+
+`search` ranks every note and function with BM25. Words in double quotes must appear next to
+each other, `--chapter ch04` (or `slice`, `ch04/slice`) keeps the results of those chapters, and
+`--limit <n>` shows more than the 10 best.
+
+The exercises live in `exercises/<chapter>/`: fix them until they compile and their hidden test
+passes. `watch` moves on to the next exercise by itself, and `.myrust-progress` remembers which
+ones are done.
+
+`layout` draws the stack bytes of an `i32`, a tuple, an array, a `&str`, a `String`, a
+`Rectangle`, the variants of `Message` and two `Option`s, with the heap buffer they point to.
+`myrust layout Option<String>` shows a single type (quote it for the shell).
+
+Every chapter is also a library, so the demos run inside this process.
+*/
+
+mod catalog;
+mod exercise;
+mod guide;
+mod layout;
+mod lexer;
+mod search;
+mod source;
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use catalog::CHAPTERS;
+use common::{JsonReporter, TextReporter};
+use exercise::Exercise;
+
+const USAGE: &str = "usage: myrust list
+       myrust run [--json] [--output <file>] [--input <line>]... <chapter|package|package::demo|demo>
+       myrust guide [--out <dir>]
+       myrust search [--chapter <chapter>] [--limit <n>] <words | \"a phrase\">...
+       myrust exercise [list | run [<name>] | hint [<name>]]
+       myrust watch
+       myrust layout [<type>]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["list"] => {
+            list();
+            Ok(())
+        }
+        ["run", ref options @ ..] => run(options),
+        ["guide"] => guide("target/study-guide"),
+        ["guide", "--out", dir] => guide(dir),
+        ["search", ref options @ ..] => search(options),
+        ["exercise"] | ["exercise", "list"] => exercise::list(root()),
+        ["exercise", "run"] => current_exercise().and_then(run_exercise),
+        ["exercise", "run", name] => exercise::find(name).and_then(run_exercise),
+        ["exercise", "hint"] => current_exercise().map(hint),
+        ["exercise", "hint", name] => exercise::find(name).map(hint),
+        ["watch"] => exercise::watch(root()),
+        ["layout"] => layout(None),
+        ["layout", name] => layout(Some(name)),
+        ["help"] | ["--help"] | ["-h"] => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn list() {
+    for chapter in CHAPTERS {
+        println!("{:<22} {}", chapter.path, chapter.description);
+        for demo in chapter.demos {
+            println!("    {:<30} {}", demo.name, demo.description);
+        }
+    }
+}
+
+fn run(options: &[&str]) -> Result<(), String> {
+    let mut json = false;
+    let mut output = None;
+    let mut target = None;
+    let mut options = options.iter();
+    while let Some(&option) = options.next() {
+        match option {
+            "--json" => json = true,
+            "--output" | "-o" => output = Some(*options.next().ok_or("`--output` needs a file")?),
+            // left in the arguments for the demos that read input, like `arrays_demo`
+            "--input" => {
+                options.next().ok_or("`--input` needs a line")?;
+            }
+            _ if option.starts_with("--input=") => {}
+            _ if target.is_none() => target = Some(option),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let target = catalog::resolve(target.ok_or(USAGE)?)?;
+
+    let writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).map_err(|err| format!("failed to create `{path}`: {err}"))?,
+        )),
+        None => Box::new(io::stdout()),
+    };
+    let written = if json {
+        let mut out = JsonReporter::new(writer);
+        target.run(&mut out);
+        out.finish()
+    } else {
+        let mut out = TextReporter::new(writer);
+        target.run(&mut out);
+        out.finish()
+    };
+    written
+        .and_then(|mut writer| writer.flush())
+        .map_err(|err| format!("failed to write the output: {err}"))
+}
+
+// The workspace this binary was built from: the notes and the exercises are read from there.
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn guide(out_dir: &str) -> Result<(), String> {
+    for path in guide::write(root(), &root().join(out_dir))? {
+        println!("wrote {path}");
+    }
+    Ok(())
+}
+
+fn search(options: &[&str]) -> Result<(), String> {
+    let mut chapter = None;
+    let mut limit = 10;
+    let mut words = Vec::new();
+    let mut options = options.iter();
+    while let Some(&option) = options.next() {
+        match option {
+            "--chapter" | "-c" => {
+                chapter = Some(*options.next().ok_or("`--chapter` needs a name")?)
+            }
+            "--limit" | "-n" => {
+                let value = options.next().ok_or("`--limit` needs a number")?;
+                limit = value
+                    .parse()
+                    .map_err(|_| format!("`--limit` needs a number, got `{value}`"))?;
+            }
+            // the shell already took the quotes off `myrust search "shallow copy"`
+            _ if option.contains(' ') && !option.contains('"') => {
+                words.push(format!("\"{option}\""))
+            }
+            _ => words.push(option.to_string()),
+        }
+    }
+    let query = search::Query::parse(&words.join(" "));
+    if query.is_empty() {
+        return Err(USAGE.to_string());
+    }
+
+    let index = search::Index::load(root())?;
+    let hits = index.search(&query, chapter);
+    if hits.is_empty() {
+        println!("no results");
+    }
+    search::print(&hits[..hits.len().min(limit)]);
+    Ok(())
+}
+
+fn layout(name: Option<&str>) -> Result<(), String> {
+    let specimens = layout::specimens()?;
+    let mut names: Vec<_> = specimens.iter().map(|layout| layout.name).collect();
+    let layouts: Vec<_> = specimens
+        .into_iter()
+        .filter(|layout| name.is_none_or(|name| layout.name.eq_ignore_ascii_case(name)))
+        .collect();
+    if layouts.is_empty() {
+        // the variants of an enum are one specimen each
+        names.dedup();
+        return Err(format!(
+            "no layout for `{}`, try one of: {}",
+            name.unwrap_or_default(),
+            names.join(", ")
+        ));
+    }
+    let rendered: Vec<String> = layouts.iter().map(layout::render).collect();
+    print!("{}", rendered.join("\n"));
+    Ok(())
+}
+
+fn current_exercise() -> Result<&'static Exercise, String> {
+    exercise::Progress::load(root())?
+        .current()
+        .ok_or_else(|| String::from("every exercise is done"))
+}
+
+fn run_exercise(exercise: &'static Exercise) -> Result<(), String> {
+    if exercise::run(root(), exercise)? {
+        Ok(())
+    } else {
+        Err(format!(
+            "edit {} and run it again, or see `myrust exercise hint {}`",
+            exercise.path(root()).display(),
+            exercise.name
+        ))
+    }
+}
+
+fn hint(exercise: &Exercise) {
+    println!("{}", exercise.hint);
 }
@@ -0,0 +1,271 @@
+// `myrust search`: full-text search over the notes and the functions of every chapter.
+//
+// Each note and each item (fn, struct, enum, trait) is a document. The index keeps, for every
+// word, the documents it appears in and its positions there, so results are ranked with BM25
+// and `"quoted phrases"` only match words that follow each other.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use common::Chapter;
+
+use crate::catalog::CHAPTERS;
+use crate::source;
+
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+pub struct Document {
+    pub chapter: &'static Chapter,
+    pub file: String,
+    // `fn move_demo_1`, or `note` for a block comment
+    pub title: String,
+    // the source lines of the document and the number of the first one
+    pub first_line: usize,
+    pub lines: Vec<String>,
+    // every word in order, with the index (into `lines`) of the line it is on
+    words: Vec<(String, usize)>,
+}
+
+pub struct Index {
+    docs: Vec<Document>,
+    // word -> (document, positions of the word in it)
+    postings: HashMap<String, Vec<(usize, Vec<usize>)>>,
+    average_len: f64,
+}
+
+pub struct Query {
+    // single words, and phrases of two or more words
+    pub terms: Vec<String>,
+    pub phrases: Vec<Vec<String>>,
+}
+
+pub struct Hit<'a> {
+    pub doc: &'a Document,
+    pub score: f64,
+    // the index (into `doc.lines`) of the line that matches best
+    pub best_line: usize,
+}
+
+// Lower case words; `first_word` is two words so that `first` and `word` find it.
+pub fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+impl Query {
+    // Words, and phrases in double quotes: `ownership "shallow copy"`.
+    pub fn parse(text: &str) -> Query {
+        let mut query = Query {
+            terms: Vec::new(),
+            phrases: Vec::new(),
+        };
+        for (i, part) in text.split('"').enumerate() {
+            let part_words: Vec<String> = words(part).collect();
+            // the odd parts are between quotes
+            if i % 2 == 1 && part_words.len() > 1 {
+                query.phrases.push(part_words.clone());
+            }
+            query.terms.extend(part_words);
+        }
+        query.terms.sort();
+        query.terms.dedup();
+        query
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl Document {
+    fn new(
+        chapter: &'static Chapter,
+        file: &str,
+        title: String,
+        first_line: usize,
+        text: String,
+    ) -> Document {
+        let lines: Vec<String> = text.lines().map(str::to_string).collect();
+        let words = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, line)| words(line).map(move |word| (word, i)))
+            .collect();
+        Document {
+            chapter,
+            file: file.to_string(),
+            title,
+            first_line,
+            lines,
+            words,
+        }
+    }
+}
+
+impl Index {
+    // Reads the sources of every chapter under `root`.
+    pub fn load(root: &Path) -> Result<Index, String> {
+        let mut docs = Vec::new();
+        for &chapter in CHAPTERS {
+            let files = source::load(root, chapter)
+                .map_err(|err| format!("failed to read `{}`: {err}", chapter.path))?;
+            for file in &files {
+                for note in file.notes() {
+                    let text = file.lines(note.line, note.end_line);
+                    docs.push(Document::new(
+                        chapter,
+                        &file.path,
+                        String::from("note"),
+                        note.line,
+                        text,
+                    ));
+                }
+                // the functions of an impl are documents of their own
+                for item in file.items().into_iter().filter(|item| item.kind != "impl") {
+                    let text = file.lines(item.line, item.end_line);
+                    let title = format!("{} {}", item.kind, item.name);
+                    docs.push(Document::new(chapter, &file.path, title, item.line, text));
+                }
+            }
+        }
+        Ok(Index::new(docs))
+    }
+
+    pub fn new(docs: Vec<Document>) -> Index {
+        let mut postings: HashMap<String, Vec<(usize, Vec<usize>)>> = HashMap::new();
+        for (d, doc) in docs.iter().enumerate() {
+            for (position, (word, _)) in doc.words.iter().enumerate() {
+                let list = postings.entry(word.clone()).or_default();
+                match list.last_mut() {
+                    Some((last, positions)) if *last == d => positions.push(position),
+                    _ => list.push((d, vec![position])),
+                }
+            }
+        }
+        let total: usize = docs.iter().map(|doc| doc.words.len()).sum();
+        let average_len = total as f64 / docs.len().max(1) as f64;
+        Index {
+            docs,
+            postings,
+            average_len,
+        }
+    }
+
+    // The documents that have at least one word of the query and every phrase of it, best
+    // first. `chapter` keeps only the documents of chapters whose path or package starts
+    // with it (`ch04`, `ch04/slice`, `slice`).
+    pub fn search(&self, query: &Query, chapter: Option<&str>) -> Vec<Hit<'_>> {
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        for term in &query.terms {
+            let Some(list) = self.postings.get(term) else {
+                continue;
+            };
+            let n = self.docs.len() as f64;
+            let with_term = list.len() as f64;
+            let idf = ((n - with_term + 0.5) / (with_term + 0.5) + 1.0).ln();
+            for (d, positions) in list {
+                let tf = positions.len() as f64;
+                let len = self.docs[*d].words.len() as f64;
+                let norm = K1 * (1.0 - B + B * len / self.average_len);
+                *scores.entry(*d).or_default() += idf * tf * (K1 + 1.0) / (tf + norm);
+            }
+        }
+
+        let mut hits: Vec<Hit> = scores
+            .into_iter()
+            .filter(|&(d, _)| {
+                let doc = &self.docs[d];
+                chapter.is_none_or(|filter| {
+                    doc.chapter.path.starts_with(filter) || doc.chapter.package.starts_with(filter)
+                })
+            })
+            .filter_map(|(d, score)| {
+                let phrase_starts: Vec<Vec<usize>> = query
+                    .phrases
+                    .iter()
+                    .map(|phrase| self.phrase_starts(d, phrase))
+                    .collect();
+                if phrase_starts.iter().any(Vec::is_empty) {
+                    return None;
+                }
+                let doc = &self.docs[d];
+                let best_line = match phrase_starts.first() {
+                    Some(starts) => doc.words[starts[0]].1,
+                    None => best_line(doc, &query.terms),
+                };
+                Some(Hit {
+                    doc,
+                    score,
+                    best_line,
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.doc.file.cmp(&b.doc.file))
+                .then(a.doc.first_line.cmp(&b.doc.first_line))
+        });
+        hits
+    }
+
+    // the positions in document `d` where every word of `phrase` follows the one before
+    fn phrase_starts(&self, d: usize, phrase: &[String]) -> Vec<usize> {
+        let positions_of = |word: &String| {
+            self.postings
+                .get(word)
+                .and_then(|list| list.iter().find(|(doc, _)| *doc == d))
+                .map_or(&[][..], |(_, positions)| positions.as_slice())
+        };
+        positions_of(&phrase[0])
+            .iter()
+            .copied()
+            .filter(|&start| {
+                phrase[1..]
+                    .iter()
+                    .enumerate()
+                    .all(|(i, word)| positions_of(word).contains(&(start + i + 1)))
+            })
+            .collect()
+    }
+}
+
+// the line with the most words of the query, the first one on a tie
+fn best_line(doc: &Document, terms: &[String]) -> usize {
+    let mut counts = vec![0; doc.lines.len()];
+    for (word, line) in &doc.words {
+        if terms.contains(word) {
+            counts[*line] += 1;
+        }
+    }
+    (0..counts.len())
+        .max_by(|&a, &b| counts[a].cmp(&counts[b]).then(b.cmp(&a)))
+        .unwrap_or(0)
+}
+
+// `file:line  title  (score)` and the best line with one line around it.
+pub fn print(hits: &[Hit]) {
+    for hit in hits {
+        let doc = hit.doc;
+        println!(
+            "{}:{}  {}  ({:.2})",
+            doc.file,
+            doc.first_line + hit.best_line,
+            doc.title,
+            hit.score
+        );
+        let first = hit.best_line.saturating_sub(1);
+        let last = (hit.best_line + 1).min(doc.lines.len() - 1);
+        for i in first..=last {
+            let marker = if i == hit.best_line { '>' } else { ' ' };
+            println!(
+                "  {marker} {:>4} | {}",
+                doc.first_line + i,
+                doc.lines[i].trim_end()
+            );
+        }
+        println!();
+    }
+}
@@ -0,0 +1,292 @@
+// Reads the sources of the chapter crates and finds what the study guide and the search are
+// built from: the `/* ... */` notes, and the items (functions, structs, enums, impls) that
+// follow them.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use common::Chapter;
+
+use crate::lexer::{self, Token, TokenKind};
+
+pub struct SourceFile {
+    // relative to the workspace root, e.g. `ch04/slice/src/lib.rs`
+    pub path: String,
+    pub text: String,
+}
+
+pub struct Note {
+    pub text: String,
+    pub line: usize,
+    pub end_line: usize,
+    pub item: Option<Item>,
+}
+
+#[derive(Clone)]
+pub struct Item {
+    pub kind: &'static str,
+    pub name: String,
+    pub line: usize,
+    pub end_line: usize,
+    pub code: String,
+}
+
+const ITEM_KEYWORDS: &[&str] = &["fn", "struct", "enum", "impl", "trait"];
+
+// Every `.rs` file under `<root>/<chapter.path>/src`, `lib.rs` first and the rest by name.
+pub fn load(root: &Path, chapter: &'static Chapter) -> io::Result<Vec<SourceFile>> {
+    let src = root.join(chapter.path).join("src");
+    let mut paths = Vec::new();
+    collect_rust_files(&src, &mut paths)?;
+    paths.sort_by_key(|path| (!path.ends_with("src/lib.rs"), path.clone()));
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let text = fs::read_to_string(&path)?;
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            Ok(SourceFile {
+                path: relative.to_string_lossy().replace('\\', "/"),
+                text,
+            })
+        })
+        .collect()
+}
+
+fn collect_rust_files(dir: &Path, paths: &mut Vec<std::path::PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_rust_files(&path, paths)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+impl SourceFile {
+    // The block comments that start a line, in order, each with the function that follows it
+    // before the next note (or the first other item when there is no function). Doc comments (`/**`, `/*!`) are not notes.
+    pub fn notes(&self) -> Vec<Note> {
+        let tokens = lexer::tokenize(&self.text);
+        let items = self.items_from(&tokens);
+        let mut notes: Vec<Note> = tokens
+            .iter()
+            .filter(|token| token.kind == TokenKind::BlockComment)
+            .filter(|token| self.starts_line(token.start))
+            .filter_map(|token| {
+                let comment = &self.text[token.start..token.end];
+                if comment.starts_with("/**") || comment.starts_with("/*!") {
+                    return None;
+                }
+                let inner = comment
+                    .strip_prefix("/*")
+                    .and_then(|c| c.strip_suffix("*/"))
+                    .unwrap_or(comment);
+                Some(Note {
+                    text: dedent(inner),
+                    line: self.line_of(token.start),
+                    end_line: self.line_of(token.end),
+                    item: None,
+                })
+            })
+            .collect();
+
+        for i in 0..notes.len() {
+            let after = notes[i].end_line;
+            let before = notes.get(i + 1).map_or(usize::MAX, |next| next.line);
+            let between: Vec<&Item> = items
+                .iter()
+                .filter(|item| item.line > after && item.line < before)
+                .collect();
+            notes[i].item = between
+                .iter()
+                .find(|item| item.kind == "fn")
+                .or(between.first())
+                .map(|item| (*item).clone());
+        }
+        notes
+    }
+
+    // Items at the top of the file, and the functions inside `impl` blocks.
+    pub fn items(&self) -> Vec<Item> {
+        self.items_from(&lexer::tokenize(&self.text))
+    }
+
+    fn items_from(&self, tokens: &[Token]) -> Vec<Item> {
+        let code: Vec<&Token> = tokens
+            .iter()
+            .filter(|token| {
+                !matches!(
+                    token.kind,
+                    TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment
+                )
+            })
+            .collect();
+        let text = |token: &Token| &self.text[token.start..token.end];
+
+        let mut items = Vec::new();
+        // the brace depth of every `impl` block we are in
+        let mut impl_depths: Vec<usize> = Vec::new();
+        let mut depth = 0;
+        let mut i = 0;
+        while i < code.len() {
+            let token = code[i];
+            match text(token) {
+                "{" => depth += 1,
+                "}" => {
+                    depth -= 1;
+                    if impl_depths.last() == Some(&depth) {
+                        impl_depths.pop();
+                    }
+                }
+                keyword
+                    if token.kind == TokenKind::Keyword
+                        && ITEM_KEYWORDS.contains(&keyword)
+                        && (depth == 0
+                            || (keyword == "fn" && impl_depths.last() == Some(&(depth - 1)))) =>
+                {
+                    let Some(end) = item_end(&code, i, &self.text) else {
+                        i += 1;
+                        continue;
+                    };
+                    let name = if keyword == "impl" {
+                        impl_name(&code[i + 1..], &self.text)
+                    } else {
+                        code.get(i + 1)
+                            .map_or(String::new(), |t| text(t).to_string())
+                    };
+                    let line = self.item_start_line(self.line_of(token.start));
+                    let end_line = self.line_of(code[end].start);
+                    items.push(Item {
+                        kind: ITEM_KEYWORDS.iter().find(|k| **k == keyword).unwrap(),
+                        name,
+                        line,
+                        end_line,
+                        code: dedent(&self.lines(line, end_line)),
+                    });
+                    if keyword == "impl" {
+                        impl_depths.push(depth);
+                        // keep walking inside the impl block to find its functions
+                    } else {
+                        i = end + 1;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        items
+    }
+
+    // 1-based line of a byte offset
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.text[..offset].matches('\n').count() + 1
+    }
+
+    // the text of the lines `first..=last`, 1-based
+    pub fn lines(&self, first: usize, last: usize) -> String {
+        self.text
+            .lines()
+            .skip(first - 1)
+            .take(last + 1 - first)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn starts_line(&self, offset: usize) -> bool {
+        let line_start = self.text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        self.text[line_start..offset].trim().is_empty()
+    }
+
+    // An item starts with the attributes and comments right above its keyword.
+    fn item_start_line(&self, keyword_line: usize) -> usize {
+        let lines: Vec<&str> = self.text.lines().collect();
+        let mut line = keyword_line;
+        while line > 1 {
+            let above = lines[line - 2].trim();
+            let attached = above.starts_with("#[") || above.starts_with("//");
+            if !attached {
+                break;
+            }
+            line -= 1;
+        }
+        line
+    }
+}
+
+// Index (into `code`) of the token that ends the item starting at `start`: its closing `}`,
+// or the `;` of items without a body like `struct AlwaysEqual;`.
+fn item_end(code: &[&Token], start: usize, text: &str) -> Option<usize> {
+    let mut nesting = 0i32;
+    let mut i = start;
+    while i < code.len() {
+        match &text[code[i].start..code[i].end] {
+            "(" | "[" => nesting += 1,
+            ")" | "]" => nesting -= 1,
+            ";" if nesting == 0 => return Some(i),
+            "{" if nesting == 0 => {
+                let mut depth = 0;
+                for (j, token) in code.iter().enumerate().skip(i) {
+                    match &text[token.start..token.end] {
+                        "{" => depth += 1,
+                        "}" => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Some(j);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                return None;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+// `impl Rectangle {` is named `Rectangle`, `impl Display for MyString {` is `Display for MyString`
+fn impl_name(after_impl: &[&Token], text: &str) -> String {
+    let mut name = String::new();
+    for token in after_impl {
+        let t = &text[token.start..token.end];
+        if t == "{" || t == "where" {
+            break;
+        }
+        if !name.is_empty() && matches!(token.kind, TokenKind::Ident | TokenKind::Keyword) {
+            name.push(' ');
+        }
+        name.push_str(t);
+    }
+    name.replace(" <", "<").replace("< ", "<")
+}
+
+// Removes the indentation every non-blank line has in common, and the blank lines around.
+pub fn dedent(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let lines: Vec<&str> = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or(""))
+        .collect();
+    let first = lines
+        .iter()
+        .position(|line| !line.is_empty())
+        .unwrap_or(lines.len());
+    let last = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(first, |i| i + 1);
+    lines[first..last].join("\n")
+}
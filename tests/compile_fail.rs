@@ -0,0 +1,96 @@
+// The compiler errors the chapters only show as commented out code.
+//
+// Every file in `tests/compile_fail/` is compiled with the local `rustc` and must fail with
+// the error code and message written in its header:
+//
+//     // expect-error: E0499
+//     // expect-message: cannot borrow `x` as mutable more than once at a time
+//
+// so a toolchain upgrade that changes one of these errors shows up here.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[test]
+fn every_case_fails_with_the_expected_error() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail");
+    let mut cases: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    cases.sort();
+    assert!(!cases.is_empty(), "no cases in {}", dir.display());
+
+    let failures: Vec<String> = cases.iter().filter_map(|case| check(case).err()).collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} compile-fail case(s) failed with {}\n\n{}",
+        failures.len(),
+        cases.len(),
+        rustc_version(),
+        failures.join("\n\n")
+    );
+}
+
+fn check(case: &Path) -> Result<(), String> {
+    let name = case.file_name().unwrap().to_string_lossy();
+    let source = fs::read_to_string(case).unwrap();
+    let code = header(&source, "expect-error")
+        .ok_or_else(|| format!("{name}: missing `// expect-error:` header"))?;
+    let message = header(&source, "expect-message")
+        .ok_or_else(|| format!("{name}: missing `// expect-message:` header"))?;
+
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("compile_fail");
+    fs::create_dir_all(&out_dir).unwrap();
+    let output = Command::new(rustc())
+        .args([
+            "--edition",
+            "2024",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .args(["--error-format", "short", "--out-dir"])
+        .arg(&out_dir)
+        .arg(case)
+        .output()
+        .map_err(|err| format!("{name}: failed to run rustc: {err}"))?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if output.status.success() {
+        return Err(format!("{name}: compiled, but should fail with {code}"));
+    }
+    let found = stderr
+        .lines()
+        .any(|line| line.contains(&format!("error[{code}]")) && line.contains(message));
+    if found {
+        Ok(())
+    } else {
+        Err(format!(
+            "{name}: expected error[{code}] with `{message}`, rustc said:\n{stderr}"
+        ))
+    }
+}
+
+fn header<'a>(source: &'a str, key: &str) -> Option<&'a str> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix("//"))
+        .find_map(|line| line.trim().strip_prefix(key)?.strip_prefix(':'))
+        .map(str::trim)
+}
+
+fn rustc() -> String {
+    std::env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"))
+}
+
+fn rustc_version() -> String {
+    Command::new(rustc())
+        .arg("--version")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|_| String::from("an unknown rustc"))
+}
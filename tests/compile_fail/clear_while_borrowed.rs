@@ -0,0 +1,20 @@
+// ch04/slice `first_word_demo`: `greet.clear()` while the slice `first` still borrows `greet`.
+// expect-error: E0502
+// expect-message: cannot borrow `greet` as mutable because it is also borrowed as immutable
+
+fn first_word(s: &String) -> &str {
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+    s
+}
+
+pub fn clear_while_borrowed() {
+    let mut greet = String::from("Hello, world!");
+    let first = first_word(&greet);
+    greet.clear();
+    println!("First word is: `{}`", first);
+}
@@ -0,0 +1,9 @@
+// ch04/borrowing `dangle`: returning a reference to a `String` that is dropped at the end of the function.
+// expect-error: E0106
+// expect-message: missing lifetime specifier
+
+pub fn dangle() -> &String {
+    let s = String::from("hello");
+
+    &s
+}
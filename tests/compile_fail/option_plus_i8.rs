@@ -0,0 +1,10 @@
+// ch06/enums: an `Option<i8>` has to become an `i8` before we can add it to one.
+// expect-error: E0277
+// expect-message: cannot add `Option<i8>` to `i8`
+
+pub fn sum() -> i8 {
+    let x: i8 = 5;
+    let y: Option<i8> = Some(5);
+
+    x + y
+}
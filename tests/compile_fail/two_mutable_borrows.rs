@@ -0,0 +1,10 @@
+// ch04/borrowing `mutable_reference_demo`: two mutable borrows of `x` alive at the same time.
+// expect-error: E0499
+// expect-message: cannot borrow `x` as mutable more than once at a time
+
+pub fn two_mutable_borrows() {
+    let mut x = String::from("foooo");
+    let r1 = &mut x;
+    let r2 = &mut x;
+    println!("{}, {}", r1, r2);
+}
@@ -0,0 +1,14 @@
+// ch04/ownership_intro `onwership_and_functions_2`: using `s2` after it moved into `take_and_give_back`.
+// expect-error: E0382
+// expect-message: borrow of moved value: `s2`
+
+fn take_and_give_back(s: String) -> String {
+    s
+}
+
+pub fn onwership_and_functions_2() {
+    let s2 = String::from("hello");
+    let s3 = take_and_give_back(s2);
+    println!("I can use s3 because it's a move from s2: s3 is: `{}`", s3);
+    println!("Definetely I cannot use s2 again.. {}", s2);
+}
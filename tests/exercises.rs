@@ -0,0 +1,44 @@
+// Every exercise must fail as shipped, and pass with its solution from `exercises/.solutions/`,
+// so a hidden test that is too loose (or too strict) shows up here.
+
+#[path = "../src/exercise.rs"]
+#[allow(dead_code)]
+mod exercise;
+
+use std::path::Path;
+
+use exercise::{EXERCISES, Outcome};
+
+#[test]
+fn every_exercise_fails_as_shipped_and_passes_when_solved() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let build_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("exercises");
+    let mut failures = Vec::new();
+
+    for exercise in EXERCISES {
+        let name = exercise.name;
+        let shipped = exercise.path(root);
+        assert!(shipped.exists(), "{name}: {} is missing", shipped.display());
+        if let Outcome::Passed(_) = exercise::check(root, exercise, &shipped, &build_dir).unwrap() {
+            failures.push(format!("{name}: passes before it is fixed"));
+        }
+
+        let solution = root.join("exercises/.solutions").join(format!("{name}.rs"));
+        match exercise::check(root, exercise, &solution, &build_dir).unwrap() {
+            Outcome::Passed(_) => {}
+            Outcome::CompileError(output) => {
+                failures.push(format!("{name}: the solution doesn't compile:\n{output}"))
+            }
+            Outcome::TestsFailed(output) => {
+                failures.push(format!("{name}: the solution fails its tests:\n{output}"))
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} exercise(s) are broken\n\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}
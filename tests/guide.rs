@@ -0,0 +1,207 @@
+// `myrust guide`: the notes of every chapter, each with the code it is about. The chapters here
+// are made up, in a workspace of their own, so that changing a chapter doesn't change them.
+
+#[path = "../src/catalog.rs"]
+#[allow(dead_code)]
+mod catalog;
+#[path = "../src/guide.rs"]
+#[allow(dead_code)]
+mod guide;
+#[path = "../src/lexer.rs"]
+#[allow(dead_code)]
+mod lexer;
+#[path = "../src/source.rs"]
+#[allow(dead_code)]
+mod source;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use catalog::CHAPTERS;
+use guide::{Section, collect, markdown};
+
+const SLICE: &str = "\
+/*
+    Slices borrow part of a collection:
+      - like `&s[0..5]`
+*/
+
+/// not a note, nor the code of one: a function comes first
+struct Unused;
+
+// the demo
+pub fn first_word_demo() {
+    let s = String::from(\"hello world\"); /* not a note either */
+}
+
+/** a doc comment, not a note */
+
+/*
+A note with nothing after it.
+*/
+";
+
+const RECTANGLES: &str = "\
+/* A struct with named fields. */
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+}
+
+impl Rectangle {
+    /*
+    The area, a method.
+    */
+    fn area(&self) -> u32 {
+        self.width
+    }
+}
+";
+
+// A workspace with a directory for every chapter under the target directory, empty but for
+// `files`.
+fn workspace(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&root);
+    for chapter in CHAPTERS {
+        fs::create_dir_all(root.join(chapter.path).join("src")).unwrap();
+    }
+    for (path, text) in files {
+        fs::write(root.join(path), text).unwrap();
+    }
+    root
+}
+
+fn sections(name: &str) -> Vec<Section> {
+    let root = workspace(
+        name,
+        &[
+            ("ch04/slice/src/lib.rs", SLICE),
+            ("ch05/rectangles/src/lib.rs", RECTANGLES),
+        ],
+    );
+    collect(&root).unwrap()
+}
+
+fn section<'a>(sections: &'a [Section], path: &str) -> &'a Section {
+    sections
+        .iter()
+        .find(|section| section.chapter.path == path)
+        .unwrap_or_else(|| panic!("no section for `{path}`"))
+}
+
+#[test]
+fn every_chapter_has_a_section_in_order() {
+    let sections = sections("guide_order");
+    let paths: Vec<&str> = sections
+        .iter()
+        .map(|section| section.chapter.path)
+        .collect();
+    let chapters: Vec<&str> = CHAPTERS.iter().map(|chapter| chapter.path).collect();
+    assert_eq!(paths, chapters);
+    assert!(section(&sections, "ch03/variables").notes.is_empty());
+}
+
+#[test]
+fn notes_are_the_block_comments_that_start_a_line() {
+    let sections = sections("guide_notes");
+    let notes = &section(&sections, "ch04/slice").notes;
+    let found: Vec<(&str, usize, &str)> = notes
+        .iter()
+        .map(|(file, note)| (file.as_str(), note.line, note.text.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                "ch04/slice/src/lib.rs",
+                1,
+                "Slices borrow part of a collection:\n  - like `&s[0..5]`"
+            ),
+            ("ch04/slice/src/lib.rs", 16, "A note with nothing after it."),
+        ]
+    );
+}
+
+#[test]
+fn a_note_is_paired_with_the_function_after_it() {
+    let sections = sections("guide_pairs");
+    let slice = &section(&sections, "ch04/slice").notes;
+    let demo = slice[0].1.item.as_ref().unwrap();
+    assert_eq!((demo.kind, demo.name.as_str()), ("fn", "first_word_demo"));
+    assert_eq!(
+        demo.code,
+        "// the demo\npub fn first_word_demo() {\n    let s = String::from(\"hello world\"); /* not a note either */\n}"
+    );
+    assert!(slice[1].1.item.is_none());
+
+    // with no function before the next note, the first item, and the functions of an impl
+    let rectangles = &section(&sections, "ch05/rectangles").notes;
+    let items: Vec<(&str, &str, &str)> = rectangles
+        .iter()
+        .map(|(_, note)| {
+            let item = note.item.as_ref().unwrap();
+            (item.kind, item.name.as_str(), item.code.as_str())
+        })
+        .collect();
+    assert_eq!(
+        items,
+        [
+            (
+                "struct",
+                "Rectangle",
+                "#[derive(Debug)]\nstruct Rectangle {\n    width: u32,\n}"
+            ),
+            ("fn", "area", "fn area(&self) -> u32 {\n    self.width\n}"),
+        ]
+    );
+}
+
+#[test]
+fn the_markdown_has_each_note_then_its_code() {
+    let sections = sections("guide_markdown");
+    let md = markdown("ch05", &[section(&sections, "ch05/rectangles")]);
+    assert!(md.starts_with("# ch05\n\n## ch05/rectangles - "), "{md}");
+    assert!(md.ends_with(
+        "\n### `fn area` (ch05/rectangles/src/lib.rs:8)\n\nThe area, a method.\n\n\
+         ```rust\nfn area(&self) -> u32 {\n    self.width\n}\n```\n"
+    ));
+
+    let md = markdown("ch04", &[section(&sections, "ch04/slice")]);
+    assert!(
+        md.ends_with("\n### Notes (ch04/slice/src/lib.rs:16)\n\nA note with nothing after it.\n"),
+        "{md}"
+    );
+}
+
+#[test]
+fn the_guide_is_a_file_per_part_and_one_page() {
+    let root = workspace("guide_write", &[("ch04/slice/src/lib.rs", SLICE)]);
+    let out = root.join("guide");
+    let written = guide::write(&root, &out).unwrap();
+    let names: Vec<String> = written
+        .iter()
+        .map(|path| {
+            Path::new(path)
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "ch03.md",
+            "ch04.md",
+            "ch05.md",
+            "ch06.md",
+            "study-guide.html"
+        ]
+    );
+
+    let html = fs::read_to_string(out.join("study-guide.html")).unwrap();
+    assert!(html.contains("<li><a href=\"#ch04-slice\">ch04/slice</a></li>"));
+    assert!(html.contains("like <code>&amp;s[0..5]</code>"), "{html}");
+    assert!(html.contains("<span class=\"kw\">pub</span> <span class=\"kw\">fn</span>"));
+}
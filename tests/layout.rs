@@ -0,0 +1,133 @@
+// `myrust layout`: what it finds out about the specimens. Addresses change on every run, so the
+// output itself is not snapshotted.
+
+#[path = "../src/layout.rs"]
+#[allow(dead_code)]
+mod layout;
+
+use layout::{Layout, render, specimens};
+
+fn find(expr: &str) -> Layout {
+    specimens()
+        .unwrap()
+        .into_iter()
+        .find(|layout| layout.expr == expr)
+        .unwrap_or_else(|| panic!("no specimen `{expr}`"))
+}
+
+#[test]
+fn copy_types_are_told_from_the_others() {
+    let copy: Vec<(&str, bool)> = specimens()
+        .unwrap()
+        .iter()
+        .map(|layout| (layout.name, layout.copy))
+        .collect();
+    for (name, expected) in [
+        ("i32", true),
+        ("tuple", true),
+        ("array", true),
+        ("&str", true),
+        ("String", false),
+        ("Rectangle", false),
+        ("Message", false),
+        ("Option<i32>", true),
+    ] {
+        assert!(
+            copy.contains(&(name, expected)),
+            "{name} should be Copy: {expected}"
+        );
+    }
+}
+
+#[test]
+fn fields_are_read_in_native_byte_order_and_padding_is_left_out() {
+    let rectangle = find("Rectangle { width: 30, height: 50 }");
+    assert_eq!((rectangle.size, rectangle.align), (8, 4));
+    let bytes: Vec<u8> = rectangle
+        .fields
+        .iter()
+        .flat_map(|field| field.bytes.clone())
+        .collect();
+    assert_eq!(bytes, [30u32.to_ne_bytes(), 50u32.to_ne_bytes()].concat());
+
+    let tuple = find("(500, 6.4, 1)");
+    let covered: usize = tuple.fields.iter().map(|field| field.bytes.len()).sum();
+    assert_eq!((tuple.size, covered), (16, 13));
+    assert!(render(&tuple).contains(".. .. .."));
+}
+
+#[test]
+fn a_string_points_to_its_heap_buffer() {
+    let string = specimens()
+        .unwrap()
+        .into_iter()
+        .find(|layout| layout.name == "String")
+        .unwrap();
+    let mut names: Vec<&str> = string
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["capacity", "len", "ptr"]);
+    let heap = string.target.as_ref().unwrap();
+    assert!(heap.heap);
+    assert_eq!((heap.bytes.as_slice(), heap.spare), (&b"hello"[..], 3));
+    assert!(render(&string).contains(" ---> "));
+
+    let literal = find("\"Ioannis\"");
+    assert!(!literal.target.unwrap().heap);
+}
+
+#[test]
+fn option_string_uses_a_niche_and_option_i32_a_tag() {
+    let none = find("None");
+    assert_eq!(none.size, size_of::<String>());
+    let names: Vec<&str> = none
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect();
+    assert_eq!(names, ["niche"]);
+    assert!(none.note.unwrap().contains("niche"));
+
+    let some = find("Some(7)");
+    assert_eq!(some.size, 8);
+    assert!(some.note.unwrap().contains("tag"));
+}
+
+#[test]
+fn the_tag_or_niche_says_the_discriminant() {
+    let discriminant = |expr: &str, name: &str| {
+        let layout = find(expr);
+        let field = layout
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .unwrap_or_else(|| panic!("no {name} in `{expr}`"));
+        (field.bytes.clone(), field.value.clone())
+    };
+    assert_eq!(
+        discriminant("Some(7)", "tag"),
+        (
+            1u32.to_ne_bytes().to_vec(),
+            String::from("1: discriminant 1, Some")
+        )
+    );
+    assert_eq!(
+        discriminant("None::<i32>", "tag"),
+        (
+            0u32.to_ne_bytes().to_vec(),
+            String::from("0: discriminant 0, None")
+        )
+    );
+
+    // `Message` keeps its variant in the capacity of `Write`'s `String`
+    let (bytes, value) = discriminant("Message::Quit", "niche");
+    assert_eq!(bytes.len(), size_of::<usize>());
+    assert!(value.ends_with(": discriminant 0, Quit"), "{value}");
+    let (_, value) = discriminant("Message::ChangeColor(0, 160, 255)", "niche");
+    assert!(value.ends_with(": discriminant 3, ChangeColor"), "{value}");
+    let (_, value) = discriminant("Message::Write(String::from(\"hello\"))", "0.capacity");
+    assert_eq!(value, "5, so discriminant 2, Write");
+}
@@ -0,0 +1,161 @@
+// `myrust search`: BM25 ranking, quoted phrases and the chapter filter, on made-up chapters in
+// a workspace of their own, so that changing a chapter doesn't change the results.
+
+#[path = "../src/catalog.rs"]
+#[allow(dead_code)]
+mod catalog;
+#[path = "../src/lexer.rs"]
+#[allow(dead_code)]
+mod lexer;
+#[path = "../src/search.rs"]
+#[allow(dead_code)]
+mod search;
+#[path = "../src/source.rs"]
+#[allow(dead_code)]
+mod source;
+
+use std::fs;
+use std::path::Path;
+
+use catalog::CHAPTERS;
+use search::{Index, Query};
+
+const SLICE: &str = "\
+/*
+Moving a String is a shallow copy:
+the pointer, the length and the capacity.
+*/
+pub fn move_demo() {
+    let s1 = String::from(\"hello\");
+    let s2 = s1;
+}
+
+pub fn heap_demo() {
+    // heap, heap
+}
+";
+
+const RECTANGLES: &str = "\
+/*
+Copy is shallow for an integer, there is no heap
+to copy from, and no pointer to follow, only bits in one place.
+*/
+pub fn area(width: u32, height: u32) -> u32 {
+    width * height
+}
+";
+
+// The index of a workspace with a directory for every chapter under the target directory,
+// empty but for two of them.
+fn index(name: &str) -> Index {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&root);
+    for chapter in CHAPTERS {
+        fs::create_dir_all(root.join(chapter.path).join("src")).unwrap();
+    }
+    fs::write(root.join("ch04/slice/src/lib.rs"), SLICE).unwrap();
+    fs::write(root.join("ch05/rectangles/src/lib.rs"), RECTANGLES).unwrap();
+    Index::load(&root).unwrap()
+}
+
+// (file, title, line) of every hit, best first
+fn search(index: &Index, query: &str, chapter: Option<&str>) -> Vec<(String, String, usize)> {
+    index
+        .search(&Query::parse(query), chapter)
+        .iter()
+        .map(|hit| {
+            let doc = hit.doc;
+            (
+                doc.file.clone(),
+                doc.title.clone(),
+                doc.first_line + hit.best_line,
+            )
+        })
+        .collect()
+}
+
+fn hit(file: &str, title: &str, line: usize) -> (String, String, usize) {
+    (file.to_string(), title.to_string(), line)
+}
+
+#[test]
+fn queries_are_lower_case_words_and_quoted_phrases() {
+    let query = Query::parse("Heap \"shallow  Copy\" first_word \"alone\" heap");
+    assert_eq!(
+        query.terms,
+        ["alone", "copy", "first", "heap", "shallow", "word"]
+    );
+    assert_eq!(query.phrases, [["shallow", "copy"]]);
+    assert!(Query::parse(" \"\" ").is_empty());
+}
+
+#[test]
+fn hits_are_ranked_by_bm25() {
+    let index = index("search_ranking");
+    let hits = index.search(&Query::parse("heap"), None);
+    let scores: Vec<f64> = hits.iter().map(|hit| hit.score).collect();
+    assert!(
+        scores.windows(2).all(|pair| pair[0] > pair[1]),
+        "{scores:?}"
+    );
+    // three times in a short function beats once in a long note, at the line with the most
+    assert_eq!(
+        search(&index, "heap", None),
+        [
+            hit("ch04/slice/src/lib.rs", "fn heap_demo", 11),
+            hit("ch05/rectangles/src/lib.rs", "note", 2),
+        ]
+    );
+    // a word in fewer documents counts more, and once each, the shorter document wins
+    assert_eq!(
+        search(&index, "width pointer", None),
+        [
+            hit("ch05/rectangles/src/lib.rs", "fn area", 5),
+            hit("ch04/slice/src/lib.rs", "note", 3),
+            hit("ch05/rectangles/src/lib.rs", "note", 3),
+        ]
+    );
+    assert!(search(&index, "borrow", None).is_empty());
+}
+
+#[test]
+fn a_phrase_only_matches_words_that_follow_each_other() {
+    let index = index("search_phrases");
+    assert_eq!(
+        search(&index, "\"shallow copy\"", None),
+        [hit("ch04/slice/src/lib.rs", "note", 2)]
+    );
+    // `Copy is shallow` has both words, not the phrase
+    let mut files: Vec<String> = search(&index, "shallow copy", None)
+        .into_iter()
+        .map(|(file, _, _)| file)
+        .collect();
+    files.sort();
+    assert_eq!(
+        files,
+        ["ch04/slice/src/lib.rs", "ch05/rectangles/src/lib.rs"]
+    );
+    assert!(search(&index, "\"copy shallow\"", None).is_empty());
+}
+
+#[test]
+fn the_chapter_filter_is_a_path_or_package_prefix() {
+    let index = index("search_chapters");
+    let heap_demo = hit("ch04/slice/src/lib.rs", "fn heap_demo", 11);
+    let note = hit("ch05/rectangles/src/lib.rs", "note", 2);
+    for chapter in ["ch04", "ch04/slice", "slice"] {
+        assert_eq!(
+            search(&index, "heap", Some(chapter)),
+            std::slice::from_ref(&heap_demo),
+            "{chapter}"
+        );
+    }
+    for chapter in ["ch05", "rect"] {
+        assert_eq!(
+            search(&index, "heap", Some(chapter)),
+            std::slice::from_ref(&note),
+            "{chapter}"
+        );
+    }
+    assert!(search(&index, "heap", Some("ch06")).is_empty());
+}
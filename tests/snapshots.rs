@@ -0,0 +1,249 @@
+// Golden output of every demo.
+//
+// Each demo runs through the `myrust` binary and its stdout is compared with
+// `tests/snapshots/<package>/<demo>.snap`, with its source line and column numbers as `LL` and
+// `CC`. A demo that panics on purpose ends with `--- panicked at ...` and the panic message.
+// When the output changed on purpose, bless it with
+//
+//     UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+
+#[path = "../src/catalog.rs"]
+#[allow(dead_code)]
+mod catalog;
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+// Demos that read stdin get these lines instead of waiting for someone to type. The bad lines
+// show that `arrays_demo` asks again.
+const SCRIPTED_STDIN: &[(&str, &str)] = &[("data_types::arrays_demo", "x\n7\n3\n")];
+
+// Demos that end in a panic, the way the chapter wrote them. Their snapshot ends with the panic
+// message.
+const PANICKING: &[&str] = &["control_flow::while_loop_demo"];
+
+#[test]
+fn every_demo_matches_its_snapshot() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some_and(|value| value == "1");
+    let mut failures = Vec::new();
+
+    for chapter in catalog::CHAPTERS {
+        for demo in chapter.demos {
+            let target = format!("{}::{}", chapter.package, demo.name);
+            let stdin = SCRIPTED_STDIN
+                .iter()
+                .find(|(name, _)| *name == target)
+                .map_or("", |(_, input)| *input);
+            let actual = snapshot(&target, stdin, &run_demo(&target, stdin));
+            let path = snapshot_path(chapter.package, demo.name);
+
+            if update {
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, &actual).unwrap();
+                continue;
+            }
+            match fs::read_to_string(&path) {
+                Ok(expected) if expected == actual => {}
+                Ok(expected) => failures.push(format!(
+                    "{target} changed ({}):\n{}",
+                    path.display(),
+                    diff(&expected, &actual)
+                )),
+                Err(_) => failures.push(format!(
+                    "{target} has no snapshot at {}, run with UPDATE_SNAPSHOTS=1 to create it",
+                    path.display()
+                )),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) failed, run with UPDATE_SNAPSHOTS=1 if the change is expected\n\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn source_locations_are_placeholders() {
+    let stdout = "Timeline:\n  172 |   s1 created\n      |   s1 dropped\n\
+                  \x20 --> ch04/borrowing/src/lib.rs:238:29: `r2`\n\
+                  at main.rs:7, not 3 | 4 or file.rs:x\n";
+    assert_eq!(
+        normalize(stdout),
+        "Timeline:\n   LL |   s1 created\n      |   s1 dropped\n\
+         \x20 --> ch04/borrowing/src/lib.rs:LL:CC: `r2`\n\
+         at main.rs:LL, not 3 | 4 or file.rs:x\n"
+    );
+}
+
+fn run_demo(target: &str, stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_myrust"))
+        .args(["run", target])
+        // the scripted stdin, not the one of whoever runs the tests
+        .env_remove("MYRUST_INPUT")
+        // a panic message without the backtrace under it
+        .env("RUST_BACKTRACE", "0")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let mut stdout = String::from_utf8(output.stdout).unwrap();
+    if !PANICKING.contains(&target) {
+        assert!(
+            output.status.success(),
+            "`myrust run {target}` failed with {}:\n{stderr}",
+            output.status
+        );
+        return stdout;
+    }
+    assert_eq!(
+        output.status.code(),
+        Some(101),
+        "`myrust run {target}` didn't panic"
+    );
+    // `thread 'main' (1234) panicked at src/lib.rs:138:41:` and the message, without the thread
+    let at = stderr
+        .find("panicked at ")
+        .unwrap_or_else(|| panic!("no panic message from `myrust run {target}`:\n{stderr}"));
+    let message: Vec<&str> = stderr[at..]
+        .lines()
+        .take_while(|line| !line.starts_with("note: "))
+        .collect();
+    stdout.push_str(&format!("--- {}\n", message.join("\n")));
+    stdout
+}
+
+fn snapshot(target: &str, stdin: &str, stdout: &str) -> String {
+    let mut snapshot = format!("myrust run {target}\n");
+    if !stdin.is_empty() {
+        snapshot.push_str(&format!("stdin: {:?}\n", stdin));
+    }
+    snapshot.push_str("---\n");
+    snapshot.push_str(&normalize(stdout));
+    snapshot
+}
+
+// The output with its source locations as placeholders, the way rustc's UI tests have them:
+// `lib.rs:211:32` as `lib.rs:LL:CC` and the `  217 | ` gutter of a trace as `   LL | `. Without
+// them every edit above a demo would change its snapshot.
+fn normalize(stdout: &str) -> String {
+    let mut normalized = String::with_capacity(stdout.len());
+    for line in stdout.split_inclusive('\n') {
+        let text = line.trim_start();
+        let indent = &line[..line.len() - text.len()];
+        let digits = leading_digits(text);
+        if digits > 0 && text[digits..].starts_with(" |") {
+            // as wide as the number was, so the gutter stays aligned with the lines without one
+            let width = indent.len() + digits;
+            normalized.push_str(&format!("{:>width$}", "LL"));
+            normalized.push_str(&normalize_locations(&text[digits..]));
+        } else {
+            normalized.push_str(&normalize_locations(line));
+        }
+    }
+    normalized
+}
+
+// `file.rs:N:M` and `file.rs:N` as `file.rs:LL:CC` and `file.rs:LL`.
+fn normalize_locations(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find(".rs:") {
+        normalized.push_str(&rest[..at + ".rs".len()]);
+        rest = &rest[at + ".rs".len()..];
+        for placeholder in ["LL", "CC"] {
+            let Some(number) = rest.strip_prefix(':') else {
+                break;
+            };
+            let digits = leading_digits(number);
+            if digits == 0 {
+                break;
+            }
+            normalized.push(':');
+            normalized.push_str(placeholder);
+            rest = &number[digits..];
+        }
+    }
+    normalized.push_str(rest);
+    normalized
+}
+
+fn leading_digits(text: &str) -> usize {
+    text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len()
+}
+
+fn snapshot_path(package: &str, demo: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(package)
+        .join(format!("{demo}.snap"))
+}
+
+// A line diff of the two outputs: ` ` for a common line, `-` for an expected line that is
+// gone and `+` for a new one. Common lines far from any change are left out.
+fn diff(expected: &str, actual: &str) -> String {
+    const CONTEXT: usize = 2;
+
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // longest common subsequence table, lcs[i][j] is for old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..lines.len()).filter(|&k| lines[k].0 != ' ').collect();
+    let mut out = String::new();
+    let mut last_shown = None;
+    for (k, (sign, line)) in lines.iter().enumerate() {
+        let near_change = changed.iter().any(|&c| c.abs_diff(k) <= CONTEXT);
+        if !near_change {
+            continue;
+        }
+        if last_shown.is_some_and(|last| last + 1 != k) {
+            out.push_str("  ...\n");
+        }
+        out.push_str(&format!("{sign} {line}\n"));
+        last_shown = Some(k);
+    }
+    if expected.ends_with('\n') != actual.ends_with('\n') {
+        out.push_str("  (the trailing newline changed)\n");
+    }
+    out
+}